@@ -21,9 +21,10 @@ use std::convert::TryFrom;
 
 use crate::{FlightData, SchemaResult};
 
+use arrow::array::ArrayRef;
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::error::{ArrowError, Result};
-use arrow::ipc::{convert, reader, writer, writer::IpcWriteOptions};
+use arrow::ipc::{self, convert, reader, writer, writer::IpcWriteOptions};
 use arrow::record_batch::RecordBatch;
 
 /// Convert a `RecordBatch` to `FlightData` by converting the header and body to bytes
@@ -51,6 +52,24 @@ pub fn flight_data_from_arrow_batch(
     }
 }
 
+/// Convert the values of a dictionary to `FlightData` by converting the header and
+/// body to bytes
+///
+/// Dictionary messages must be sent before the record batches that reference them.
+pub fn flight_data_from_arrow_dictionary(
+    dict_id: i64,
+    values: &ArrayRef,
+    options: &IpcWriteOptions,
+) -> FlightData {
+    let data = writer::dictionary_batch_to_bytes(dict_id, values, &options);
+    FlightData {
+        flight_descriptor: None,
+        app_metadata: vec![],
+        data_header: data.ipc_message,
+        data_body: data.arrow_data,
+    }
+}
+
 /// Convert a `Schema` to `SchemaResult` by converting to an IPC message
 ///
 /// Note: This implicitly uses the default `IpcWriteOptions`. To configure options,
@@ -125,33 +144,206 @@ impl TryFrom<&SchemaResult> for Schema {
     }
 }
 
-/// Convert a FlightData message to a RecordBatch
-pub fn flight_data_to_arrow_batch(
+/// Convert a `FlightData` message to a `RecordBatch`
+///
+/// Returns `Ok(Some(batch))` if the message is a record batch, and `Ok(None)` if it
+/// is a schema or dictionary message, which carry no rows. A stream that only
+/// contains a schema (e.g. an empty result set) therefore decodes to no batches.
+///
+/// The values of dictionary messages are stored in `dictionaries_by_field`, which
+/// holds one entry per field of `schema`, and are used to resolve the
+/// dictionary-encoded columns of the record batches that follow. The same slice
+/// should thus be passed to every call for a given stream.
+///
+/// Returns an error if the `FlightData` header is of any other message type, or if
+/// a record batch references a dictionary that has not been received.
+pub fn flight_data_to_batch(
     data: &FlightData,
     schema: SchemaRef,
-) -> Option<Result<RecordBatch>> {
-    // check that the data_header is a record batch message
+    dictionaries_by_field: &mut [Option<ArrayRef>],
+) -> Result<Option<RecordBatch>> {
     let message = arrow::ipc::get_root_as_message(&data.data_header[..]);
-    let dictionaries_by_field = Vec::new();
 
-    message
-        .header_as_record_batch()
-        .ok_or_else(|| {
-            ArrowError::ParseError(
-                "Unable to convert flight data header to a record batch".to_string(),
+    match message.header_type() {
+        ipc::MessageHeader::Schema => Ok(None),
+        ipc::MessageHeader::DictionaryBatch => {
+            let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                ArrowError::ParseError(
+                    "Unable to convert flight data header to a dictionary batch"
+                        .to_string(),
+                )
+            })?;
+            reader::read_dictionary(
+                &data.data_body,
+                batch,
+                &schema,
+                dictionaries_by_field,
+            )?;
+            Ok(None)
+        }
+        ipc::MessageHeader::RecordBatch => {
+            let batch = message.header_as_record_batch().ok_or_else(|| {
+                ArrowError::ParseError(
+                    "Unable to convert flight data header to a record batch".to_string(),
+                )
+            })?;
+            let missing = schema
+                .fields()
+                .iter()
+                .zip(dictionaries_by_field.iter())
+                .find(|(field, dictionary)| {
+                    field.dict_id().is_some() && dictionary.is_none()
+                });
+            if let Some((field, _)) = missing {
+                return Err(ArrowError::ParseError(format!(
+                    "No dictionary with id {} received for field {}",
+                    field.dict_id().unwrap(),
+                    field.name()
+                )));
+            }
+            reader::read_record_batch(
+                &data.data_body,
+                batch,
+                schema,
+                dictionaries_by_field,
             )
-        })
-        .map_or_else(
-            |err| Some(Err(err)),
-            |batch| {
-                Some(reader::read_record_batch(
-                    &data.data_body,
-                    batch,
-                    schema,
-                    &dictionaries_by_field,
-                ))
-            },
-        )
+            .map(Some)
+        }
+        t => Err(ArrowError::ParseError(format!(
+            "Unable to convert flight data with header type {:?} to a record batch",
+            t
+        ))),
+    }
 }
 
 // TODO: add more explicit conversion that expoess flight descriptor and metadata options
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use arrow::array::{
+        Array, DictionaryArray, Int32Array, PrimitiveArrayOps, StringArray,
+    };
+    use arrow::datatypes::{DataType, Field, Int8Type};
+
+    #[test]
+    fn schema_message_decodes_to_no_batch() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let flight_data = FlightData::from(&schema);
+
+        let mut dictionaries_by_field = vec![None; schema.fields().len()];
+        let batch = flight_data_to_batch(
+            &flight_data,
+            Arc::new(schema),
+            &mut dictionaries_by_field,
+        )?;
+        assert!(batch.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn record_batch_message_round_trip() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)])?;
+        let flight_data = FlightData::from(&batch);
+
+        let mut dictionaries_by_field = vec![None; schema.fields().len()];
+        let decoded =
+            flight_data_to_batch(&flight_data, schema, &mut dictionaries_by_field)?
+                .unwrap();
+        assert_eq!(3, decoded.num_rows());
+        // decoded buffers are padded to 8 bytes, so compare values rather than data
+        let decoded = decoded
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(1, decoded.null_count());
+        assert_eq!(1, decoded.value(0));
+        assert!(decoded.is_null(1));
+        assert_eq!(3, decoded.value(2));
+        Ok(())
+    }
+
+    #[test]
+    fn dictionary_message_resolves_batch() -> Result<()> {
+        let data_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new_dict("a", data_type, false, 1, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let dict_array: DictionaryArray<Int8Type> =
+            vec!["foo", "bar", "foo", "baz"].into_iter().collect();
+        let dict_values = dict_array.values();
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(dict_array),
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            ],
+        )?;
+
+        let options = IpcWriteOptions::default();
+        let dictionary_data =
+            flight_data_from_arrow_dictionary(1, &dict_values, &options);
+        let batch_data = flight_data_from_arrow_batch(&batch, &options);
+
+        let mut dictionaries_by_field = vec![None; schema.fields().len()];
+        let decoded = flight_data_to_batch(
+            &dictionary_data,
+            schema.clone(),
+            &mut dictionaries_by_field,
+        )?;
+        assert!(decoded.is_none());
+        assert!(dictionaries_by_field[0].is_some());
+        assert!(dictionaries_by_field[1].is_none());
+
+        let decoded =
+            flight_data_to_batch(&batch_data, schema, &mut dictionaries_by_field)?
+                .unwrap();
+        let keys = decoded
+            .column(0)
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        let values = keys.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        let decoded_values: Vec<&str> = keys
+            .keys()
+            .map(|key| values.value(key.unwrap() as usize))
+            .collect();
+        assert_eq!(vec!["foo", "bar", "foo", "baz"], decoded_values);
+        let b = decoded
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&[1, 2, 3, 4], b.value_slice(0, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn batch_before_dictionary_is_error() {
+        let data_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![Field::new_dict(
+            "a", data_type, false, 1, false,
+        )]));
+        let dict_array: DictionaryArray<Int8Type> = vec!["foo"].into_iter().collect();
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(dict_array)]).unwrap();
+
+        let mut dictionaries_by_field = vec![None; schema.fields().len()];
+        let result = flight_data_to_batch(
+            &FlightData::from(&batch),
+            schema,
+            &mut dictionaries_by_field,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 use std::io::Write;
 use std::iter::{FromIterator, IntoIterator};
 use std::mem;
+use std::ops::Range;
 use std::sync::Arc;
 
 use chrono::prelude::*;
@@ -220,6 +221,26 @@ pub trait Array: fmt::Debug + Send + Sync + ArrayEqual + JsonEqual {
         self.data_ref().null_count()
     }
 
+    /// Returns the number of null values in this array, counted from the validity
+    /// bitmap over the range of the array instead of read from the stored null count.
+    ///
+    /// This is correct even when the stored null count of the underlying data does not
+    /// match its offset and length.
+    fn logical_null_count(&self) -> usize {
+        let data = self.data_ref();
+        match data.null_buffer() {
+            Some(buffer) => {
+                data.len()
+                    - bit_util::count_set_bits_offset(
+                        buffer.data(),
+                        data.offset(),
+                        data.len(),
+                    )
+            }
+            None => 0,
+        }
+    }
+
     /// Returns the total number of bytes of memory occupied by the buffers owned by this array.
     fn get_buffer_memory_size(&self) -> usize;
 
@@ -299,6 +320,7 @@ pub fn make_array(data: ArrayDataRef) -> ArrayRef {
         }
         DataType::Utf8 => Arc::new(StringArray::from(data)) as ArrayRef,
         DataType::LargeUtf8 => Arc::new(LargeStringArray::from(data)) as ArrayRef,
+        DataType::Utf8View => Arc::new(StringViewArray::from(data)) as ArrayRef,
         DataType::List(_) => Arc::new(ListArray::from(data)) as ArrayRef,
         DataType::LargeList(_) => Arc::new(LargeListArray::from(data)) as ArrayRef,
         DataType::Struct(_) => Arc::new(StructArray::from(data)) as ArrayRef,
@@ -496,10 +518,203 @@ impl<T: ArrowNumericType> PrimitiveArray<T> {
         &raw[..]
     }
 
+    /// Returns the value at index `i`, or `None` if the slot is null or `i` is out
+    /// of bounds.
+    ///
+    /// Unlike `value`, this checks both the index and the validity of the slot.
+    pub fn get(&self, i: usize) -> Option<T::Native> {
+        if i < self.len() && self.is_valid(i) {
+            Some(self.value(i))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an array of length `len` whose slot `i` is `f(i)`, or null if `f(i)`
+    /// returns `None`.
+    ///
+    /// The buffers are allocated once, up front.
+    pub fn from_fn<F: Fn(usize) -> Option<T::Native>>(len: usize, f: F) -> Self {
+        (0..len).map(f).collect()
+    }
+
+    /// Creates an array without nulls from an iterator of values.
+    ///
+    /// The lower bound of the iterator's size hint is used to reserve memory up front,
+    /// and the buffer grows if the iterator yields more values.
+    pub fn from_iter_values<I: IntoIterator<Item = T::Native>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut val_buf = MutableBuffer::new(lower * mem::size_of::<T::Native>());
+        let mut len = 0;
+        for v in iter {
+            // `write` never grows the buffer, so make room for the value first
+            val_buf
+                .reserve(val_buf.len() + mem::size_of::<T::Native>())
+                .unwrap();
+            val_buf.write_all(v.to_byte_slice()).unwrap();
+            len += 1;
+        }
+
+        let data = ArrayData::builder(T::DATA_TYPE)
+            .len(len)
+            .add_buffer(val_buf.freeze())
+            .build();
+        PrimitiveArray::from(data)
+    }
+
+    /// Creates an array from an iterator of optional values whose size hint reports
+    /// its exact length.
+    ///
+    /// Unlike collecting into a `PrimitiveArray`, the null buffer is only allocated
+    /// once the first `None` is seen, so an iterator without nulls produces an array
+    /// without a null buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has no upper bound in its size hint, or if it yields a
+    /// different number of items than that upper bound.
+    pub fn from_trusted_len_iter<I: IntoIterator<Item = Option<T::Native>>>(
+        iter: I,
+    ) -> Self {
+        let iter = iter.into_iter();
+        let (_, upper) = iter.size_hint();
+        let len = upper.expect("from_trusted_len_iter requires an upper limit");
+
+        let num_bytes = bit_util::ceil(len, 8);
+        let mut null_buf: Option<MutableBuffer> = None;
+        let mut val_buf = MutableBuffer::new(len * mem::size_of::<T::Native>());
+        let mut count = 0;
+        for (i, item) in iter.enumerate() {
+            match item {
+                Some(v) => {
+                    val_buf.write_all(v.to_byte_slice()).unwrap();
+                    if let Some(null_buf) = null_buf.as_mut() {
+                        bit_util::set_bit(null_buf.data_mut(), i);
+                    }
+                }
+                None => {
+                    if null_buf.is_none() {
+                        // all the previous slots are valid
+                        let mut buf =
+                            MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+                        let bits = buf.data_mut();
+                        (0..i).for_each(|j| bit_util::set_bit(bits, j));
+                        null_buf = Some(buf);
+                    }
+                    val_buf
+                        .write_all(T::default_value().to_byte_slice())
+                        .unwrap();
+                }
+            }
+            count += 1;
+        }
+        assert_eq!(
+            len, count,
+            "the iterator yielded a different number of items than its size hint"
+        );
+
+        let mut builder = ArrayData::builder(T::DATA_TYPE)
+            .len(len)
+            .add_buffer(val_buf.freeze());
+        if let Some(null_buf) = null_buf {
+            builder = builder.null_bit_buffer(null_buf.freeze());
+        }
+        PrimitiveArray::from(builder.build())
+    }
+
     // Returns a new primitive array builder
     pub fn builder(capacity: usize) -> PrimitiveBuilder<T> {
         PrimitiveBuilder::<T>::new(capacity)
     }
+
+    /// Converts this array into a builder that appends to its values, without copying.
+    ///
+    /// This only succeeds if the array is the sole owner of its data and buffers and is
+    /// not a slice, as shared buffers can't be mutated. The array is consumed either way.
+    pub fn into_builder(self) -> Result<PrimitiveBuilder<T>> {
+        let shared_error = || {
+            ArrowError::InvalidArgumentError(
+                "Cannot convert an array with shared buffers into a builder".to_string(),
+            )
+        };
+        if self.offset() != 0 {
+            return Err(shared_error());
+        }
+        let len = self.len();
+        let data = Arc::try_unwrap(self.data).map_err(|_| shared_error())?;
+        let (buffers, bitmap) = data.into_buffers();
+        let values = buffers
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(
+                    "Primitive arrays should have 1 buffer".to_string(),
+                )
+            })?
+            .into_mutable()
+            .map_err(|_| shared_error())?;
+        let bitmap = match bitmap {
+            Some(bitmap) => Some(bitmap.into_mutable().map_err(|_| shared_error())?),
+            None => None,
+        };
+        PrimitiveBuilder::<T>::from_buffers(values, bitmap, len)
+    }
+
+    /// Applies `f` to the valid values of this array, mutating its values buffer in
+    /// place instead of allocating a new one.
+    ///
+    /// This only succeeds if the array is the sole owner of its data and values buffer,
+    /// as shared buffers can't be mutated. The array is consumed either way.
+    pub fn map_values_in_place<F>(self, f: F) -> Result<PrimitiveArray<T>>
+    where
+        F: Fn(T::Native) -> T::Native,
+        T::Native: num::Num,
+    {
+        let shared_error = || {
+            ArrowError::InvalidArgumentError(
+                "Cannot mutate an array with shared buffers in place".to_string(),
+            )
+        };
+        let data_type = self.data_type().clone();
+        let len = self.len();
+        let offset = self.offset();
+        let null_count = self.null_count();
+        let data = Arc::try_unwrap(self.data).map_err(|_| shared_error())?;
+        let (buffers, bitmap) = data.into_buffers();
+        let mut values = buffers
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(
+                    "Primitive arrays should have 1 buffer".to_string(),
+                )
+            })?
+            .into_mutable()
+            .map_err(|_| shared_error())?;
+
+        let slots = values.typed_data_mut::<T::Native>();
+        for i in offset..offset + len {
+            let is_valid = bitmap
+                .as_ref()
+                .map(|bitmap| bit_util::get_bit(bitmap.data(), i))
+                .unwrap_or(true);
+            if is_valid {
+                slots[i] = f(slots[i]);
+            }
+        }
+
+        let data = ArrayData::new(
+            data_type,
+            len,
+            Some(null_count),
+            bitmap,
+            offset,
+            vec![values.freeze()],
+            vec![],
+        );
+        Ok(PrimitiveArray::from(Arc::new(data)))
+    }
 }
 
 fn as_datetime<T: ArrowPrimitiveType>(v: i64) -> Option<NaiveDateTime> {
@@ -679,11 +894,100 @@ impl<'a, T: ArrowPrimitiveType> IntoIterator for &'a PrimitiveArray<T> {
     }
 }
 
+impl<'a, OffsetSize: StringOffsetSizeTrait> IntoIterator
+    for &'a GenericStringArray<OffsetSize>
+{
+    type Item = Option<&'a str>;
+    type IntoIter = GenericStringIter<'a, OffsetSize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        GenericStringIter::<'a, OffsetSize>::new(self)
+    }
+}
+
 impl<'a, T: ArrowPrimitiveType> PrimitiveArray<T> {
     /// constructs a new iterator
     pub fn iter(&'a self) -> PrimitiveIter<'a, T> {
         PrimitiveIter::<'a, T>::new(&self)
     }
+
+    /// Returns the logical values in `range` as a vector, with `None` for null slots.
+    ///
+    /// Unlike `value_slice`, this copies the values and takes the validity into
+    /// account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of the array.
+    pub fn values_vec(&self, range: Range<usize>) -> Vec<Option<T::Native>> {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "range {:?} out of bounds of array of length {}",
+            range,
+            self.len()
+        );
+        range
+            .map(|i| {
+                if self.is_valid(i) {
+                    Some(self.value(i))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Creates an array of `len` values starting at slot `offset` of the `values` and
+    /// `nulls` buffers, such as buffers imported through the C data interface.
+    ///
+    /// Unlike building the `ArrayData` directly, this returns an error instead of
+    /// creating an unsound array if `values` is too small or misaligned for
+    /// `offset + len` values, or if `nulls` has fewer than `offset + len` bits.
+    pub fn try_from_raw(
+        values: Buffer,
+        nulls: Option<Buffer>,
+        len: usize,
+        offset: usize,
+    ) -> Result<Self> {
+        let slots = offset.checked_add(len).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Offset {} and length {} overflow usize",
+                offset, len
+            ))
+        })?;
+        let values_len = if T::DATA_TYPE == DataType::Boolean {
+            Some(bit_util::ceil(slots, 8))
+        } else {
+            slots.checked_mul(mem::size_of::<T::Native>())
+        };
+        if values_len.map(|l| values.len() < l).unwrap_or(true) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Values buffer of {} bytes is too small for {} values of type {:?}",
+                values.len(),
+                slots,
+                T::DATA_TYPE
+            )));
+        }
+        if !memory::is_aligned::<u8>(values.raw_data(), mem::align_of::<T::Native>()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Values buffer is not aligned to {} bytes",
+                mem::align_of::<T::Native>()
+            )));
+        }
+        if let Some(nulls) = &nulls {
+            if nulls.len() < bit_util::ceil(slots, 8) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Null buffer of {} bytes is too small for {} slots",
+                    nulls.len(),
+                    slots
+                )));
+            }
+        }
+
+        let data =
+            ArrayData::new(T::DATA_TYPE, len, None, nulls, offset, vec![values], vec![]);
+        Ok(PrimitiveArray::from(Arc::new(data)))
+    }
 }
 
 impl<T: ArrowPrimitiveType, Ptr: Borrow<Option<<T as ArrowPrimitiveType>::Native>>>
@@ -774,8 +1078,10 @@ def_numeric_from_vec!(DurationSecondType);
 def_numeric_from_vec!(DurationMillisecondType);
 def_numeric_from_vec!(DurationMicrosecondType);
 def_numeric_from_vec!(DurationNanosecondType);
+def_numeric_from_vec!(TimestampSecondType);
 def_numeric_from_vec!(TimestampMillisecondType);
 def_numeric_from_vec!(TimestampMicrosecondType);
+def_numeric_from_vec!(TimestampNanosecondType);
 
 impl<T: ArrowTimestampType> PrimitiveArray<T> {
     /// Construct a timestamp array from a vec of i64 values and an optional timezone
@@ -936,13 +1242,45 @@ impl<OffsetSize: OffsetSizeTrait> GenericListArray<OffsetSize> {
         self.values.clone()
     }
 
+    /// Returns the values of this list as a `PrimitiveArray<T>`.
+    ///
+    /// Returns an error if the values are not of type `T`.
+    pub fn values_as<T: ArrowPrimitiveType>(&self) -> Result<&PrimitiveArray<T>> {
+        self.values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Cannot access list values of type {:?} as {:?}",
+                    self.value_type(),
+                    T::DATA_TYPE
+                ))
+            })
+    }
+
+    /// Returns the values of this list as a `ListArray`, for navigating nested lists.
+    ///
+    /// Returns an error if the values are not a `List`.
+    pub fn values_as_list(&self) -> Result<&ListArray> {
+        self.values
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Cannot access list values of type {:?} as a list",
+                    self.value_type()
+                ))
+            })
+    }
+
     /// Returns a clone of the value type of this list.
     pub fn value_type(&self) -> DataType {
         self.values.data_ref().data_type().clone()
     }
 
-    /// Returns ith value of this list array.
+    /// Returns ith value of this list array as a zero-copy slice of the values array.
     pub fn value(&self, i: usize) -> ArrayRef {
+        assert!(i < self.data.len(), "ListArray out of bounds access");
         self.values.slice(
             self.value_offset(i).to_usize().unwrap(),
             self.value_length(i).to_usize().unwrap(),
@@ -1044,7 +1382,7 @@ where
         if array.len() > 20 {
             writeln!(f, "  ...{} elements...,", array.len() - 20)?;
         }
-        for i in array.len() - 10..array.len() {
+        for i in std::cmp::max(10, array.len() - 10)..array.len() {
             if array.is_null(i) {
                 writeln!(f, "  null,")?;
             } else {
@@ -1503,6 +1841,11 @@ impl<OffsetSize: StringOffsetSizeTrait> GenericStringArray<OffsetSize> {
         }
     }
 
+    /// constructs a new iterator over the optional values of this array
+    pub fn iter(&self) -> GenericStringIter<'_, OffsetSize> {
+        GenericStringIter::<OffsetSize>::new(self)
+    }
+
     fn from_list(v: GenericListArray<OffsetSize>, data_type: DataType) -> Self {
         assert_eq!(
             v.data().child_data()[0].child_data().len(),
@@ -2432,13 +2775,220 @@ mod tests {
         }
 
         assert_eq!(128, arr.get_buffer_memory_size());
-        let internals_of_primitive_array = 8 + 72 + 16; // RawPtrBox & Arc<ArrayData> and it's null_bitmap combined.
+        // RawPtrBox & Arc<ArrayData> and its null_bitmap combined, where the bitmap is a
+        // Buffer of an Arc, an offset and a length.
+        let internals_of_primitive_array = 8 + 72 + 24;
         assert_eq!(
             arr.get_buffer_memory_size() + internals_of_primitive_array,
             arr.get_array_memory_size()
         );
     }
 
+    #[test]
+    fn test_primitive_array_from_vec_native_types() {
+        let arr = UInt8Array::from(vec![1, 2, 255]);
+        assert_eq!(&DataType::UInt8, arr.data_type());
+        assert_eq!(0, arr.null_count());
+        assert_eq!(255, arr.value(2));
+        let arr = UInt8Array::from(vec![Some(1), None, Some(255)]);
+        assert_eq!(1, arr.null_count());
+        assert_eq!(255, arr.value(2));
+
+        let arr = Int64Array::from(vec![i64::MIN, 0, i64::MAX]);
+        assert_eq!(&DataType::Int64, arr.data_type());
+        assert_eq!(i64::MIN, arr.value(0));
+        assert_eq!(i64::MAX, arr.value(2));
+        let arr = Int64Array::from(vec![None, Some(i64::MAX)]);
+        assert!(arr.is_null(0));
+        assert_eq!(i64::MAX, arr.value(1));
+
+        let arr = Float64Array::from(vec![0.5, -1.5]);
+        assert_eq!(&DataType::Float64, arr.data_type());
+        assert!((arr.value(1) + 1.5).abs() < f64::EPSILON);
+        let arr = Float64Array::from(vec![Some(0.5), None]);
+        assert!((arr.value(0) - 0.5).abs() < f64::EPSILON);
+        assert!(arr.is_null(1));
+
+        let arr = TimestampSecondArray::from(vec![Some(1), None]);
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Second, None),
+            arr.data_type()
+        );
+        assert_eq!(1, arr.null_count());
+        let arr = TimestampNanosecondArray::from(vec![1, 2]);
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Nanosecond, None),
+            arr.data_type()
+        );
+        assert_eq!(2, arr.value(1));
+    }
+
+    #[test]
+    fn test_primitive_array_into_builder() {
+        let arr = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let mut builder = arr.into_builder().unwrap();
+        builder.append_value(4).unwrap();
+        builder.append_null().unwrap();
+        let arr = builder.finish();
+
+        assert_eq!(5, arr.len());
+        assert_eq!(2, arr.null_count());
+        assert_eq!(1, arr.value(0));
+        assert!(arr.is_null(1));
+        assert_eq!(3, arr.value(2));
+        assert_eq!(4, arr.value(3));
+        assert!(arr.is_null(4));
+
+        // arrays without a null bitmap
+        let arr = Int64Array::from(vec![10, 20]);
+        let mut builder = arr.into_builder().unwrap();
+        builder.append_slice(&[30]).unwrap();
+        let arr = builder.finish();
+        assert_eq!(0, arr.null_count());
+        assert_eq!(&[10, 20, 30], arr.value_slice(0, 3));
+    }
+
+    #[test]
+    fn test_primitive_array_into_builder_shared() {
+        let arr = Int32Array::from(vec![1, 2, 3]);
+        let _other = Int32Array::from(arr.data());
+        assert!(arr.into_builder().is_err());
+
+        let arr = Int32Array::from(vec![1, 2, 3]);
+        let sliced = arr.slice(1, 2);
+        drop(arr);
+        let sliced = Int32Array::from(sliced.data());
+        assert!(sliced.into_builder().is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_map_values_in_place() {
+        let arr = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let values = arr.raw_values();
+        let arr = arr.map_values_in_place(|v| v * 2).unwrap();
+        assert_eq!(values, arr.raw_values());
+        assert_eq!(Int32Array::from(vec![Some(2), None, Some(6)]), arr);
+
+        let arr = Float64Array::from(vec![1.5, 2.5]);
+        let arr = arr.map_values_in_place(|v| -v).unwrap();
+        assert_eq!(&[-1.5, -2.5], arr.value_slice(0, 2));
+
+        let arr = Int32Array::from(vec![1, 2, 3]);
+        let _other = Int32Array::from(arr.data());
+        assert!(arr.map_values_in_place(|v| v + 1).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_try_from_raw() {
+        let values = Buffer::from(&[1, 2, 3, 4].to_byte_slice());
+        let nulls = Buffer::from([0b00001011]);
+        let arr = Int32Array::try_from_raw(values.clone(), Some(nulls), 3, 1).unwrap();
+        assert_eq!(Int32Array::from(vec![Some(2), None, Some(4)]), arr);
+
+        let arr = Int32Array::try_from_raw(values.clone(), None, 4, 0).unwrap();
+        assert_eq!(0, arr.null_count());
+        assert_eq!(&[1, 2, 3, 4], arr.value_slice(0, 4));
+
+        // the values buffer holds 4 values, not 5
+        assert!(Int32Array::try_from_raw(values.clone(), None, 4, 1).is_err());
+        assert!(Int64Array::try_from_raw(values.clone(), None, 3, 0).is_err());
+        assert!(Int32Array::try_from_raw(values, Some(Buffer::empty()), 1, 0).is_err());
+
+        let arr =
+            BooleanArray::try_from_raw(Buffer::from([0b00000101]), None, 3, 0).unwrap();
+        assert_eq!(BooleanArray::from(vec![true, false, true]), arr);
+        assert!(BooleanArray::try_from_raw(Buffer::from([0]), None, 9, 0).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_from_iter_values() {
+        let arr = Int32Array::from_iter_values(0..1000);
+        assert_eq!(1000, arr.len());
+        assert_eq!(0, arr.null_count());
+        assert!(arr.data_ref().null_buffer().is_none());
+        for i in 0..1000 {
+            assert_eq!(i as i32, arr.value(i));
+        }
+
+        // iterators without an exact size hint
+        let arr =
+            Float64Array::from_iter_values((0..10).filter(|v| v % 2 == 0).map(f64::from));
+        assert_eq!(5, arr.len());
+        assert_eq!(&[0.0, 2.0, 4.0, 6.0, 8.0], arr.value_slice(0, 5));
+    }
+
+    #[test]
+    fn test_primitive_array_from_trusted_len_iter() {
+        let arr = Int32Array::from_trusted_len_iter((0..10).map(Some));
+        assert_eq!(10, arr.len());
+        assert_eq!(0, arr.null_count());
+        assert!(arr.data_ref().null_buffer().is_none());
+        assert_eq!(Int32Array::from((0..10).collect::<Vec<i32>>()), arr);
+
+        let values = vec![Some(1.0), Some(2.0), None, Some(4.0), None];
+        let arr = Float64Array::from_trusted_len_iter(values.clone());
+        assert_eq!(2, arr.null_count());
+        assert!(arr.data_ref().null_buffer().is_some());
+        assert_eq!(Float64Array::from(values), arr);
+    }
+
+    #[test]
+    #[should_panic(expected = "requires an upper limit")]
+    fn test_primitive_array_from_trusted_len_iter_unbounded() {
+        Int32Array::from_trusted_len_iter(std::iter::repeat(Some(1)));
+    }
+
+    #[test]
+    fn test_primitive_array_from_fn() {
+        let arr =
+            Int32Array::from_fn(5, |i| if i % 2 == 0 { Some(i as i32) } else { None });
+        assert_eq!(
+            Int32Array::from(vec![Some(0), None, Some(2), None, Some(4)]),
+            arr
+        );
+        assert_eq!(2, arr.null_count());
+
+        assert_eq!(0, Float64Array::from_fn(0, |i| Some(i as f64)).len());
+    }
+
+    #[test]
+    fn test_primitive_array_values_vec() {
+        let arr = Int32Array::from(vec![10, 20, 30, 40]);
+        assert_eq!(vec![Some(20), Some(30)], arr.values_vec(1..3));
+        assert!(arr.values_vec(4..4).is_empty());
+
+        let arr = Int32Array::from(vec![Some(1), None, Some(3), None]);
+        let arr = arr.slice(1, 3);
+        let arr = arr.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(vec![None, Some(3), None], arr.values_vec(0..3));
+
+        let arr = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        assert_eq!(vec![Some(true), None, Some(false)], arr.values_vec(0..3));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_primitive_array_values_vec_out_of_bounds() {
+        let arr = Int32Array::from(vec![10, 20]);
+        arr.values_vec(1..3);
+    }
+
+    #[test]
+    fn test_primitive_array_get() {
+        let arr = Int32Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(Some(1), arr.get(0));
+        assert_eq!(None, arr.get(1));
+        assert_eq!(Some(3), arr.get(2));
+        assert_eq!(None, arr.get(3));
+
+        // indices are relative to the offset of a sliced array
+        let arr = arr.slice(1, 2);
+        let arr = arr.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(None, arr.get(0));
+        assert_eq!(Some(3), arr.get(1));
+        assert_eq!(None, arr.get(2));
+    }
+
     #[test]
     fn test_date64_array_from_vec_option() {
         // Test building a primitive array with null values
@@ -2635,6 +3185,37 @@ mod tests {
         assert_eq!(6, int_arr.value(2));
     }
 
+    #[test]
+    fn test_array_ref_clone_and_slice() {
+        let arr: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("ccc"),
+            Some("dd"),
+        ]));
+        let cloned = arr.clone();
+
+        // cloning an `ArrayRef` only bumps the reference count
+        assert!(Arc::ptr_eq(&arr, &cloned));
+        assert_eq!(2, Arc::strong_count(&arr));
+
+        let sliced = cloned.slice(1, 3);
+        assert_eq!(3, sliced.len());
+        assert_eq!(1, sliced.offset());
+        assert_eq!(1, sliced.null_count());
+
+        // the slice shares its buffers with the original array
+        assert_eq!(
+            arr.data_ref().buffers()[1].raw_data(),
+            sliced.data_ref().buffers()[1].raw_data()
+        );
+
+        let str_arr = sliced.as_any().downcast_ref::<StringArray>().unwrap();
+        assert!(str_arr.is_null(0));
+        assert_eq!("ccc", str_arr.value(1));
+        assert_eq!("dd", str_arr.value(2));
+    }
+
     #[test]
     fn test_boolean_array_slice() {
         let arr = BooleanArray::from(vec![
@@ -2705,6 +3286,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_int32_from_option_fmt_debug() {
+        let arr = Int32Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(
+            "PrimitiveArray<Int32>\n[\n  1,\n  null,\n  3,\n]",
+            format!("{:?}", arr)
+        );
+    }
+
+    #[test]
+    fn test_int32_long_fmt_debug() {
+        let arr = Int32Array::from((0..15).collect::<Vec<i32>>());
+        let expected = format!(
+            "PrimitiveArray<Int32>\n[\n{}]",
+            (0..15).map(|i| format!("  {},\n", i)).collect::<String>()
+        );
+        assert_eq!(expected, format!("{:?}", arr));
+
+        let arr = Int32Array::from((0..25).collect::<Vec<i32>>());
+        let expected = format!(
+            "PrimitiveArray<Int32>\n[\n{}  ...5 elements...,\n{}]",
+            (0..10).map(|i| format!("  {},\n", i)).collect::<String>(),
+            (15..25).map(|i| format!("  {},\n", i)).collect::<String>()
+        );
+        assert_eq!(expected, format!("{:?}", arr));
+    }
+
     #[test]
     fn test_boolean_fmt_debug() {
         let buf = Buffer::from(&[true, false, false].to_byte_slice());
@@ -2933,6 +3541,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_data_ref_inspects_buffers_uniformly() {
+        // describes the data of any array through the shared `ArrayData`
+        fn describe(array: &Array) -> (DataType, Vec<usize>, usize) {
+            let data = array.data_ref();
+            (
+                data.data_type().clone(),
+                data.buffers().iter().map(|b| b.len()).collect(),
+                data.child_data().len(),
+            )
+        }
+
+        let primitive = Int32Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!((DataType::Int32, vec![12], 0), describe(&primitive));
+        assert!(Arc::ptr_eq(primitive.data_ref(), &primitive.data()));
+
+        let mut builder = ListBuilder::new(Int32Builder::new(4));
+        builder.values().append_slice(&[1, 2, 3]).unwrap();
+        builder.append(true).unwrap();
+        builder.values().append_value(4).unwrap();
+        builder.append(true).unwrap();
+        let list = builder.finish();
+        assert_eq!(
+            (DataType::List(Box::new(DataType::Int32)), vec![12], 1),
+            describe(&list)
+        );
+        let offsets = unsafe { list.data_ref().buffers()[0].typed_data::<i32>() };
+        assert_eq!(&[0, 3, 4], offsets);
+        let values = &list.data_ref().child_data()[0];
+        assert_eq!(4, values.len());
+        let values = unsafe { values.buffers()[0].typed_data::<i32>() };
+        assert_eq!(&[1, 2, 3, 4], values);
+    }
+
     #[test]
     fn test_large_list_array() {
         // Construct a value array
@@ -3171,6 +3813,83 @@ mod tests {
         FixedSizeListArray::from(list_data);
     }
 
+    #[test]
+    fn test_list_array_values_as() {
+        let value_data = ArrayData::builder(DataType::Int32)
+            .len(6)
+            .add_buffer(Buffer::from(&[0, 1, 2, 3, 4, 5].to_byte_slice()))
+            .build();
+        let value_offsets = Buffer::from(&[0, 3, 6].to_byte_slice());
+        let list_data_type = DataType::List(Box::new(DataType::Int32));
+        let list_data = ArrayData::builder(list_data_type)
+            .len(2)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build();
+        let list_array = ListArray::from(list_data);
+
+        let values = list_array.values_as::<Int32Type>().unwrap();
+        assert_eq!(6, values.len());
+        assert_eq!(&[0, 1, 2, 3, 4, 5], values.value_slice(0, 6));
+
+        assert!(list_array.values_as::<Int64Type>().is_err());
+        assert!(list_array.values_as_list().is_err());
+    }
+
+    #[test]
+    fn test_logical_null_count() {
+        // [1, null, 3, null, 5] viewed from offset 1, with a stale null count of 0
+        let data = ArrayData::new(
+            DataType::Int32,
+            3,
+            Some(0),
+            Some(Buffer::from([0b00010101])),
+            1,
+            vec![Buffer::from(&[1, 0, 3, 0, 5].to_byte_slice())],
+            vec![],
+        );
+        let array = Int32Array::from(Arc::new(data));
+        assert_eq!(0, array.null_count());
+        assert_eq!(2, array.logical_null_count());
+
+        let sliced = array.slice(1, 2);
+        assert_eq!(1, sliced.logical_null_count());
+        assert_eq!(0, Int32Array::from(vec![1, 2]).logical_null_count());
+    }
+
+    #[test]
+    fn test_list_array_values_as_list() {
+        // [[[0, 1], [2]], [[3, 4, 5]]]
+        let value_data = ArrayData::builder(DataType::Int32)
+            .len(6)
+            .add_buffer(Buffer::from(&[0, 1, 2, 3, 4, 5].to_byte_slice()))
+            .build();
+        let inner_data_type = DataType::List(Box::new(DataType::Int32));
+        let inner_data = ArrayData::builder(inner_data_type.clone())
+            .len(3)
+            .add_buffer(Buffer::from(&[0, 2, 3, 6].to_byte_slice()))
+            .add_child_data(value_data)
+            .build();
+        let outer_data = ArrayData::builder(DataType::List(Box::new(inner_data_type)))
+            .len(2)
+            .add_buffer(Buffer::from(&[0, 2, 3].to_byte_slice()))
+            .add_child_data(inner_data)
+            .build();
+        let outer = ListArray::from(outer_data);
+
+        let inner = outer.values_as_list().unwrap();
+        assert_eq!(3, inner.len());
+        assert_eq!(3, inner.value_offset(2));
+        assert_eq!(3, inner.value_length(2));
+
+        let last = inner.value(2);
+        let last = last.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&[3, 4, 5], last.value_slice(0, 3));
+
+        assert!(inner.values_as_list().is_err());
+        assert_eq!(6, inner.values_as::<Int32Type>().unwrap().len());
+    }
+
     #[test]
     fn test_list_array_slice() {
         // Construct a value array
@@ -3390,6 +4109,42 @@ mod tests {
         ListArray::from(list_data);
     }
 
+    #[test]
+    fn test_list_array_value() {
+        let mut builder = ListBuilder::new(Int32Builder::new(5));
+        builder.values().append_slice(&[1, 2]).unwrap();
+        builder.append(true).unwrap();
+        builder.values().append_slice(&[3, 4, 5]).unwrap();
+        builder.append(true).unwrap();
+        let list_array = builder.finish();
+
+        let value = list_array.value(1);
+        let value = value.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(3, value.len());
+        assert_eq!(&[3, 4, 5], value.value_slice(0, 3));
+
+        // the value is a slice of the child array rather than a copy
+        assert_eq!(2, value.offset());
+        assert_eq!(list_array.values().data().buffers(), value.data().buffers());
+
+        let sliced = list_array.slice(1, 1);
+        let sliced = sliced.as_any().downcast_ref::<ListArray>().unwrap();
+        let value = sliced.value(0);
+        let value = value.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&[3, 4, 5], value.value_slice(0, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "ListArray out of bounds access")]
+    fn test_list_array_value_index_out_of_bound() {
+        let mut builder = ListBuilder::new(Int32Builder::new(2));
+        builder.values().append_slice(&[1, 2]).unwrap();
+        builder.append(true).unwrap();
+        let list_array = builder.finish();
+
+        list_array.value(1);
+    }
+
     #[test]
     #[should_panic(expected = "offsets do not start at zero")]
     fn test_list_array_invalid_value_offset_start() {
@@ -4280,4 +5035,44 @@ mod tests {
         assert_eq!(1, keys.value(2));
         assert_eq!(0, keys.value(5));
     }
+
+    #[test]
+    fn test_array_metadata_through_array_ref() {
+        let mut list_builder = ListBuilder::new(Int32Builder::new(4));
+        list_builder.values().append_slice(&[1, 2]).unwrap();
+        list_builder.append(true).unwrap();
+        list_builder.append(false).unwrap();
+        let list = list_builder.finish();
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(list),
+            Arc::new(StringArray::from(vec!["a", "b", "c", "d"])).slice(1, 2),
+        ];
+
+        let summary: Vec<String> = arrays
+            .iter()
+            .map(|a| {
+                format!(
+                    "{:?} len={} nulls={} offset={}",
+                    a.data_type(),
+                    a.len(),
+                    a.null_count(),
+                    a.offset()
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                "Int32 len=3 nulls=1 offset=0",
+                "List(Int32) len=2 nulls=1 offset=0",
+                "Utf8 len=2 nulls=0 offset=1",
+            ],
+            summary
+        );
+
+        assert!(arrays[0].is_null(1));
+        assert!(arrays[1].is_null(1));
+        assert!(!arrays[2].is_null(0));
+    }
 }
@@ -252,6 +252,63 @@ pub trait BufferBuilderTrait<T: ArrowPrimitiveType> {
     fn finish(&mut self) -> Buffer;
 }
 
+impl<T: ArrowPrimitiveType> BufferBuilder<T> {
+    /// Appends the `len` values yielded by `iter` into the builder, reserving memory
+    /// once and writing each value without checking the capacity.
+    ///
+    /// # Safety
+    ///
+    /// `iter` must yield exactly `len` values. Values past `len` are ignored, but if the
+    /// iterator yields fewer values the builder will contain uninitialized slots.
+    pub unsafe fn append_trusted_len_iter<I: Iterator<Item = T::Native>>(
+        &mut self,
+        len: usize,
+        iter: I,
+    ) -> Result<()> {
+        self.reserve(len)?;
+        if T::DATA_TYPE == DataType::Boolean {
+            let data = self.buffer.raw_data_mut();
+            for (i, v) in iter.take(len).enumerate() {
+                if v != T::default_value() {
+                    bit_util::set_bit_raw(data, self.len + i);
+                }
+            }
+        } else {
+            let start = self.buffer.len();
+            let dst = self.buffer.raw_data_mut().add(start) as *mut T::Native;
+            for (i, v) in iter.take(len).enumerate() {
+                std::ptr::write(dst.add(i), v);
+            }
+            self.buffer
+                .resize(start + len * mem::size_of::<T::Native>())?;
+        }
+        self.len += len;
+        Ok(())
+    }
+
+    /// Creates a builder that appends to the first `len` slots stored in `buffer`
+    fn from_buffer(mut buffer: MutableBuffer, len: usize) -> Result<Self> {
+        if T::DATA_TYPE == DataType::Boolean {
+            // appends expect all the bits past `len` to be unset
+            let num_bytes = bit_util::ceil(len, 8);
+            buffer.resize(num_bytes)?;
+            if len % 8 != 0 {
+                let last = &mut buffer.data_mut()[num_bytes - 1];
+                *last &= (1u8 << (len % 8)) - 1;
+            }
+            let capacity = buffer.capacity();
+            buffer.set_null_bits(num_bytes, capacity - num_bytes);
+        } else {
+            buffer.resize(len * mem::size_of::<T::Native>())?;
+        }
+        Ok(Self {
+            buffer,
+            len,
+            _marker: PhantomData,
+        })
+    }
+}
+
 impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
     #[inline]
     fn new(capacity: usize) -> Self {
@@ -299,7 +356,12 @@ impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
 
     #[inline]
     fn reserve(&mut self, n: usize) -> Result<()> {
-        let new_capacity = self.len + n;
+        let new_capacity = self.len.checked_add(n).ok_or_else(|| {
+            ArrowError::MemoryError(format!(
+                "Cannot reserve {} more slots in a builder of length {}",
+                n, self.len
+            ))
+        })?;
         if T::DATA_TYPE == DataType::Boolean {
             if new_capacity > self.capacity() {
                 let new_byte_capacity = bit_util::ceil(new_capacity, 8);
@@ -309,7 +371,15 @@ impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
                     .set_null_bits(existing_capacity, new_capacity - existing_capacity);
             }
         } else {
-            let byte_capacity = mem::size_of::<T::Native>() * new_capacity;
+            let byte_capacity = mem::size_of::<T::Native>()
+                .checked_mul(new_capacity)
+                .ok_or_else(|| {
+                    ArrowError::MemoryError(format!(
+                        "Capacity of {} slots of {} bytes overflows usize",
+                        new_capacity,
+                        mem::size_of::<T::Native>()
+                    ))
+                })?;
             self.buffer.reserve(byte_capacity)?;
         }
         Ok(())
@@ -319,6 +389,12 @@ impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
     fn append(&mut self, v: T::Native) -> Result<()> {
         self.reserve(1)?;
         if T::DATA_TYPE == DataType::Boolean {
+            debug_assert!(
+                self.len < self.capacity(),
+                "bit {} is out of the reserved capacity {}",
+                self.len,
+                self.capacity()
+            );
             if v != T::default_value() {
                 unsafe {
                     bit_util::set_bit_raw(self.buffer.raw_data_mut(), self.len);
@@ -335,6 +411,7 @@ impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
     fn append_n(&mut self, n: usize, v: T::Native) -> Result<()> {
         self.reserve(n)?;
         if T::DATA_TYPE == DataType::Boolean {
+            debug_assert!(self.len + n <= self.capacity());
             if n != 0 && v != T::default_value() {
                 unsafe {
                     bit_util::set_bits_raw(
@@ -359,6 +436,7 @@ impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
         self.reserve(array_slots)?;
 
         if T::DATA_TYPE == DataType::Boolean {
+            debug_assert!(self.len + array_slots <= self.capacity());
             for v in slice {
                 if *v != T::default_value() {
                     // For performance the `len` of the buffer is not
@@ -411,6 +489,36 @@ impl<T: ArrowPrimitiveType> BufferBuilder<T> {
             Ok(())
         }
     }
+
+    /// Returns the number of set bits of a boolean builder, without copying its
+    /// contents.
+    fn count_set_bits(&self) -> usize {
+        debug_assert_eq!(T::DATA_TYPE, DataType::Boolean);
+        // boolean appends do not update the buffer's `len`, so read the bytes
+        // directly rather than through `MutableBuffer::data`
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                self.buffer.raw_data(),
+                bit_util::ceil(self.len, 8),
+            )
+        };
+        bit_util::count_set_bits_offset(bytes, 0, self.len)
+    }
+
+    /// Copies the contents of this builder into a new immutable `Buffer`,
+    /// leaving the builder untouched.
+    fn finish_cloned(&self) -> Buffer {
+        let byte_len = if T::DATA_TYPE == DataType::Boolean {
+            bit_util::ceil(self.len, 8)
+        } else {
+            self.len * mem::size_of::<T::Native>()
+        };
+        // boolean appends do not update the buffer's `len`, so read the
+        // bytes directly rather than through `MutableBuffer::data`
+        let bytes =
+            unsafe { std::slice::from_raw_parts(self.buffer.raw_data(), byte_len) };
+        Buffer::from(bytes)
+    }
 }
 
 /// Trait for dealing with different array builders at runtime
@@ -452,11 +560,37 @@ pub trait ArrayBuilder: Any {
     fn into_box_any(self: Box<Self>) -> Box<Any>;
 }
 
+/// Statistics of the values appended to a `PrimitiveBuilder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ColumnStatistics<N: ArrowNativeType> {
+    /// The smallest non-null value, or `None` if there are no non-null values
+    pub min: Option<N>,
+    /// The largest non-null value, or `None` if there are no non-null values
+    pub max: Option<N>,
+    /// The number of null values
+    pub null_count: usize,
+}
+
+impl<N: ArrowNativeType> ColumnStatistics<N> {
+    fn update(&mut self, v: N) {
+        match self.min {
+            Some(min) if min <= v => {}
+            _ => self.min = Some(v),
+        }
+        match self.max {
+            Some(max) if max >= v => {}
+            _ => self.max = Some(v),
+        }
+    }
+}
+
 ///  Array builder for fixed-width primitive types
 #[derive(Debug)]
 pub struct PrimitiveBuilder<T: ArrowPrimitiveType> {
     values_builder: BufferBuilder<T>,
     bitmap_builder: BooleanBufferBuilder,
+    nullable: bool,
+    statistics: Option<ColumnStatistics<T::Native>>,
 }
 
 impl<T: ArrowPrimitiveType> ArrayBuilder for PrimitiveBuilder<T> {
@@ -505,9 +639,16 @@ impl<T: ArrowPrimitiveType> ArrayBuilder for PrimitiveBuilder<T> {
             }
             total_len += array.len();
         }
+        if !self.nullable && data.iter().any(|array| array.null_count() > 0) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot append data with nulls to a non-nullable builder".to_string(),
+            ));
+        }
         // reserve memory
         self.values_builder.reserve(total_len)?;
-        self.bitmap_builder.reserve(total_len)?;
+        if self.nullable {
+            self.bitmap_builder.reserve(total_len)?;
+        }
 
         let mul = T::get_bit_width() / 8;
         for array in data {
@@ -529,11 +670,16 @@ impl<T: ArrowPrimitiveType> ArrayBuilder for PrimitiveBuilder<T> {
                     .write_bytes(&sliced[(offset * mul)..((len + offset) * mul)], len)?;
             }
 
-            for i in 0..len {
-                // account for offset as `ArrayData` does not
-                self.bitmap_builder.append(array.is_valid(offset + i))?;
+            if self.nullable {
+                for i in 0..len {
+                    // account for offset as `ArrayData` does not
+                    self.bitmap_builder.append(array.is_valid(offset + i))?;
+                }
             }
         }
+        if self.statistics.is_some() {
+            self.statistics = Some(self.compute_statistics());
+        }
         Ok(())
     }
 
@@ -556,7 +702,89 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
         Self {
             values_builder: BufferBuilder::<T>::new(capacity),
             bitmap_builder: BooleanBufferBuilder::new(capacity),
+            nullable: true,
+            statistics: None,
+        }
+    }
+
+    /// Creates a builder that appends to the first `len` values of `values`, whose
+    /// validity is given by the packed `bitmap`, or all valid if there is no bitmap.
+    pub(crate) fn from_buffers(
+        values: MutableBuffer,
+        bitmap: Option<MutableBuffer>,
+        len: usize,
+    ) -> Result<Self> {
+        let values_builder = BufferBuilder::<T>::from_buffer(values, len)?;
+        let bitmap_builder = match bitmap {
+            Some(bitmap) => BooleanBufferBuilder::from_buffer(bitmap, len)?,
+            None => {
+                let mut bitmap_builder = BooleanBufferBuilder::new(len);
+                bitmap_builder.append_n(len, true)?;
+                bitmap_builder
+            }
+        };
+        Ok(Self {
+            values_builder,
+            bitmap_builder,
+            nullable: true,
+            statistics: None,
+        })
+    }
+
+    /// Turns this builder into one that keeps track of the minimum, maximum and null
+    /// count of the appended values, so that they can be read with `statistics`
+    /// without another pass over the data.
+    ///
+    /// Values appended before this call are included in the statistics.
+    pub fn with_statistics(mut self) -> Self {
+        self.statistics = Some(self.compute_statistics());
+        self
+    }
+
+    /// Returns the statistics of the values appended since the builder was last
+    /// finished, or `None` if the builder was not created `with_statistics`.
+    pub fn statistics(&self) -> Option<ColumnStatistics<T::Native>> {
+        self.statistics.clone()
+    }
+
+    /// Computes the statistics of all the slots in the builder
+    fn compute_statistics(&self) -> ColumnStatistics<T::Native> {
+        let mut statistics = ColumnStatistics::default();
+        let values = self.values_builder.buffer.raw_data() as *const T::Native;
+        let bitmap = self.bitmap_builder.buffer.raw_data();
+        for i in 0..self.len() {
+            if !self.nullable || unsafe { bit_util::get_bit_raw(bitmap, i) } {
+                statistics.update(unsafe { T::index(values, i) });
+            } else {
+                statistics.null_count += 1;
+            }
         }
+        statistics
+    }
+
+    /// Turns this builder into one that does not track validity.
+    ///
+    /// A non-nullable builder has no null bitmap, rejects null values and always
+    /// produces arrays without a null buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a null value was already appended to the builder.
+    pub fn non_nullable(mut self) -> Self {
+        let len = self.len();
+        assert_eq!(
+            len,
+            bit_util::count_set_bits(self.bitmap_builder.finish().data()),
+            "Cannot make a builder containing nulls non-nullable"
+        );
+        self.bitmap_builder = BooleanBufferBuilder::new(0);
+        self.nullable = false;
+        self
+    }
+
+    /// Returns whether this builder accepts null values
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
     }
 
     /// Returns the capacity of this builder measured in slots of type `T`
@@ -564,17 +792,41 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
         self.values_builder.capacity()
     }
 
+    /// Returns the number of null slots appended to this builder so far
+    pub fn null_count(&self) -> usize {
+        if self.nullable {
+            self.len() - self.bitmap_builder.count_set_bits()
+        } else {
+            0
+        }
+    }
+
     /// Appends a value of type `T` into the builder
     pub fn append_value(&mut self, v: T::Native) -> Result<()> {
-        self.bitmap_builder.append(true)?;
+        if self.nullable {
+            self.bitmap_builder.append(true)?;
+        }
         self.values_builder.append(v)?;
+        if let Some(ref mut statistics) = self.statistics {
+            statistics.update(v);
+        }
         Ok(())
     }
 
     /// Appends a null slot into the builder
+    ///
+    /// Returns an error if the builder is non-nullable.
     pub fn append_null(&mut self) -> Result<()> {
+        if !self.nullable {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot append a null value to a non-nullable builder".to_string(),
+            ));
+        }
         self.bitmap_builder.append(false)?;
         self.values_builder.advance(1)?;
+        if let Some(ref mut statistics) = self.statistics {
+            statistics.null_count += 1;
+        }
         Ok(())
     }
 
@@ -589,8 +841,49 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
 
     /// Appends a slice of type `T` into the builder
     pub fn append_slice(&mut self, v: &[T::Native]) -> Result<()> {
-        self.bitmap_builder.append_n(v.len(), true)?;
+        if self.nullable {
+            self.bitmap_builder.append_n(v.len(), true)?;
+        }
         self.values_builder.append_slice(v)?;
+        if let Some(ref mut statistics) = self.statistics {
+            v.iter().for_each(|v| statistics.update(*v));
+        }
+        Ok(())
+    }
+
+    /// Appends a slice of `Option<T>` into the builder, reserving space for the whole
+    /// slice up front
+    ///
+    /// Returns an error if the builder is non-nullable and the slice contains `None`.
+    pub fn append_option_slice(&mut self, v: &[Option<T::Native>]) -> Result<()> {
+        if self.nullable {
+            self.bitmap_builder.reserve(v.len())?;
+        } else if v.iter().any(|item| item.is_none()) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot append a null value to a non-nullable builder".to_string(),
+            ));
+        }
+        self.values_builder.reserve(v.len())?;
+        for item in v {
+            match item {
+                Some(value) => {
+                    if self.nullable {
+                        self.bitmap_builder.append(true)?;
+                    }
+                    self.values_builder.append(*value)?;
+                    if let Some(ref mut statistics) = self.statistics {
+                        statistics.update(*value);
+                    }
+                }
+                None => {
+                    self.bitmap_builder.append(false)?;
+                    self.values_builder.advance(1)?;
+                    if let Some(ref mut statistics) = self.statistics {
+                        statistics.null_count += 1;
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
@@ -605,18 +898,176 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
                 "Value and validity lengths must be equal".to_string(),
             ));
         }
-        self.bitmap_builder.append_slice(is_valid)?;
-        self.values_builder.append_slice(values)
+        if self.nullable {
+            self.bitmap_builder.append_slice(is_valid)?;
+        } else if is_valid.iter().any(|v| !v) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot append a null value to a non-nullable builder".to_string(),
+            ));
+        }
+        self.values_builder.append_slice(values)?;
+        if let Some(ref mut statistics) = self.statistics {
+            for (v, is_valid) in values.iter().zip(is_valid) {
+                if *is_valid {
+                    statistics.update(*v);
+                } else {
+                    statistics.null_count += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends `len` values from an already encoded values `buffer`, copying its bytes
+    /// directly rather than pushing one value at a time.
+    ///
+    /// `buffer` must hold exactly `len` values. If `validity` is given, its first `len`
+    /// bits mark which of the appended slots are valid, otherwise all of them are.
+    pub fn append_values_buffer(
+        &mut self,
+        buffer: &Buffer,
+        len: usize,
+        validity: Option<&Buffer>,
+    ) -> Result<()> {
+        let expected_len = if T::DATA_TYPE == DataType::Boolean {
+            bit_util::ceil(len, 8)
+        } else {
+            len * mem::size_of::<T::Native>()
+        };
+        if buffer.len() != expected_len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Values buffer of {} bytes does not hold {} values of type {:?}",
+                buffer.len(),
+                len,
+                T::DATA_TYPE
+            )));
+        }
+        if let Some(validity) = validity {
+            if validity.len() < bit_util::ceil(len, 8) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Validity buffer of {} bytes is too small for {} slots",
+                    validity.len(),
+                    len
+                )));
+            }
+            if !self.nullable
+                && bit_util::count_set_bits_offset(validity.data(), 0, len) != len
+            {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Cannot append a null value to a non-nullable builder".to_string(),
+                ));
+            }
+        }
+
+        if T::DATA_TYPE == DataType::Boolean {
+            let values_builder = &mut self.values_builder;
+            values_builder.advance(len)?;
+            let start = values_builder.len - len;
+            let bits = values_builder.buffer.raw_data_mut();
+            (0..len)
+                .filter(|i| bit_util::get_bit(buffer.data(), *i))
+                .for_each(|i| unsafe { bit_util::set_bit_raw(bits, start + i) });
+        } else {
+            self.values_builder.reserve(len)?;
+            self.values_builder.write_bytes(buffer.data(), len)?;
+        }
+        if self.nullable {
+            match validity {
+                Some(validity) => {
+                    self.bitmap_builder.reserve(len)?;
+                    for i in 0..len {
+                        self.bitmap_builder
+                            .append(bit_util::get_bit(validity.data(), i))?;
+                    }
+                }
+                None => self.bitmap_builder.append_n(len, true)?,
+            }
+        }
+        if self.statistics.is_some() {
+            self.statistics = Some(self.compute_statistics());
+        }
+        Ok(())
+    }
+
+    /// Replaces the validity of all slots in the builder with the first `len` bits of
+    /// the packed bitmap `buffer`.
+    ///
+    /// This is useful to combine values appended to this builder with the validity of
+    /// another array. `len` must equal the number of slots in the builder.
+    pub fn set_validity_from(&mut self, buffer: &Buffer, len: usize) -> Result<()> {
+        if !self.nullable {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot set the validity of a non-nullable builder".to_string(),
+            ));
+        }
+        if len != self.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Validity of length {} does not match the builder length {}",
+                len,
+                self.len()
+            )));
+        }
+        let num_bytes = bit_util::ceil(len, 8);
+        if buffer.len() < num_bytes {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Validity buffer of {} bytes is too small for {} slots",
+                buffer.len(),
+                len
+            )));
+        }
+
+        let mut bitmap_builder = BooleanBufferBuilder::new(len);
+        let bits = bitmap_builder.buffer.raw_data_mut();
+        unsafe {
+            std::ptr::copy_nonoverlapping(buffer.raw_data(), bits, num_bytes);
+            // clear the bits past `len` so that later appends start from zero
+            if len % 8 != 0 {
+                *bits.add(num_bytes - 1) &= (1u8 << (len % 8)) - 1;
+            }
+        }
+        bitmap_builder.len = len;
+        self.bitmap_builder = bitmap_builder;
+        if self.statistics.is_some() {
+            self.statistics = Some(self.compute_statistics());
+        }
+        Ok(())
     }
 
     /// Builds the `PrimitiveArray` and reset this builder.
     pub fn finish(&mut self) -> PrimitiveArray<T> {
+        if self.statistics.is_some() {
+            self.statistics = Some(ColumnStatistics::default());
+        }
         let len = self.len();
+        let mut builder = ArrayData::builder(T::DATA_TYPE)
+            .len(len)
+            .add_buffer(self.values_builder.finish());
+        if !self.nullable {
+            return PrimitiveArray::<T>::from(builder.build());
+        }
         let null_bit_buffer = self.bitmap_builder.finish();
         let null_count = len - bit_util::count_set_bits(null_bit_buffer.data());
+        if null_count > 0 {
+            builder = builder
+                .null_count(null_count)
+                .null_bit_buffer(null_bit_buffer);
+        }
+        let data = builder.build();
+        PrimitiveArray::<T>::from(data)
+    }
+
+    /// Builds the `PrimitiveArray` from a copy of the current contents, without
+    /// resetting this builder.
+    pub fn finish_cloned(&self) -> PrimitiveArray<T> {
+        let len = self.len();
         let mut builder = ArrayData::builder(T::DATA_TYPE)
             .len(len)
-            .add_buffer(self.values_builder.finish());
+            .add_buffer(self.values_builder.finish_cloned());
+        if !self.nullable {
+            return PrimitiveArray::<T>::from(builder.build());
+        }
+        let null_bit_buffer = self.bitmap_builder.finish_cloned();
+        let null_count = len - bit_util::count_set_bits(null_bit_buffer.data());
         if null_count > 0 {
             builder = builder
                 .null_count(null_count)
@@ -630,7 +1081,11 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
     pub fn finish_dict(&mut self, values: ArrayRef) -> DictionaryArray<T> {
         let len = self.len();
         let null_bit_buffer = self.bitmap_builder.finish();
-        let null_count = len - bit_util::count_set_bits(null_bit_buffer.data());
+        let null_count = if self.nullable {
+            len - bit_util::count_set_bits(null_bit_buffer.data())
+        } else {
+            0
+        };
         let data_type = DataType::Dictionary(
             Box::new(T::DATA_TYPE),
             Box::new(values.data_type().clone()),
@@ -1832,8 +2287,8 @@ impl LargeStringBuilder {
 }
 
 impl FixedSizeBinaryBuilder {
-    /// Creates a new `BinaryBuilder`, `capacity` is the number of bytes in the values
-    /// array
+    /// Creates a new `FixedSizeBinaryBuilder`, `capacity` is the number of bytes in the
+    /// values array
     pub fn new(capacity: usize, byte_width: i32) -> Self {
         let values_builder = UInt8Builder::new(capacity);
         Self {
@@ -1847,9 +2302,12 @@ impl FixedSizeBinaryBuilder {
     /// distinct array element.
     pub fn append_value(&mut self, value: &[u8]) -> Result<()> {
         if self.builder.value_length() != value.len() as i32 {
-            return Err(ArrowError::InvalidArgumentError(
-                "Byte slice does not have the same length as FixedSizeBinaryBuilder value lengths".to_string()
-            ));
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Byte slice of length {} does not have the same length as \
+                 FixedSizeBinaryBuilder value lengths ({})",
+                value.len(),
+                self.builder.value_length()
+            )));
         }
         self.builder.values().append_slice(value)?;
         self.builder.append(true)
@@ -2025,7 +2483,7 @@ impl StructBuilder {
         Self::new(schema.fields, builders)
     }
 
-    fn from_field(f: Field, capacity: usize) -> Box<ArrayBuilder> {
+    pub(crate) fn from_field(f: Field, capacity: usize) -> Box<ArrayBuilder> {
         match f.data_type() {
             DataType::Null => unimplemented!(),
             DataType::Boolean => Box::new(BooleanBuilder::new(capacity)),
@@ -2524,6 +2982,24 @@ mod tests {
         assert_eq!(32, b.capacity());
     }
 
+    #[test]
+    fn test_reserve_overflow() {
+        let mut b = Int32BufferBuilder::new(2);
+        b.append(1).unwrap();
+        match b.reserve(usize::MAX / 2) {
+            Err(ArrowError::MemoryError(_)) => {}
+            other => panic!("expected a memory error, got {:?}", other),
+        }
+        match b.reserve(usize::MAX) {
+            Err(ArrowError::MemoryError(_)) => {}
+            other => panic!("expected a memory error, got {:?}", other),
+        }
+        // the builder is still usable after a failed reservation
+        assert_eq!(16, b.capacity());
+        b.append(2).unwrap();
+        assert_eq!(2, b.len());
+    }
+
     #[test]
     fn test_append_slice() {
         let mut b = UInt8BufferBuilder::new(0);
@@ -2538,6 +3014,42 @@ mod tests {
         assert_eq!(8, buffer.len());
     }
 
+    #[test]
+    fn test_append_trusted_len_iter() -> Result<()> {
+        let values: Vec<i32> = (0..1000).map(|i| i * 3).collect();
+
+        let mut expected = Int32BufferBuilder::new(0);
+        expected.append(-1)?;
+        expected.append_slice(&values)?;
+
+        let mut builder = Int32BufferBuilder::new(0);
+        builder.append(-1)?;
+        unsafe { builder.append_trusted_len_iter(1000, values.iter().cloned())? };
+        assert_eq!(1001, builder.len());
+        builder.append(7)?;
+        expected.append(7)?;
+
+        assert_eq!(expected.finish().data(), builder.finish().data());
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_trusted_len_iter_boolean() -> Result<()> {
+        let values: Vec<bool> = (0..1000).map(|i| i % 3 == 0).collect();
+
+        let mut expected = BooleanBufferBuilder::new(0);
+        expected.append(true)?;
+        expected.append_slice(&values)?;
+
+        let mut builder = BooleanBufferBuilder::new(0);
+        builder.append(true)?;
+        unsafe { builder.append_trusted_len_iter(1000, values.iter().cloned())? };
+        assert_eq!(1001, builder.len());
+
+        assert_eq!(expected.finish().data(), builder.finish().data());
+        Ok(())
+    }
+
     #[test]
     fn test_append_values() -> Result<()> {
         let mut a = Int8Builder::new(0);
@@ -2646,6 +3158,48 @@ mod tests {
         assert_eq!(buf.data(), buf2.data());
     }
 
+    #[test]
+    fn test_boolean_builder_grows_past_capacity() {
+        let mut builder = BooleanBufferBuilder::new(1);
+        let initial_capacity = builder.capacity();
+        let len = initial_capacity + 70;
+        for i in 0..len {
+            builder.append(i % 3 == 0).unwrap();
+        }
+        assert_eq!(len, builder.len());
+        assert!(builder.capacity() >= len);
+
+        let buf = builder.finish();
+        assert_eq!(bit_util::ceil(len, 8), buf.len());
+        for i in 0..len {
+            assert_eq!(i % 3 == 0, bit_util::get_bit(buf.data(), i), "bit {}", i);
+        }
+    }
+
+    #[test]
+    fn test_append_values_buffer() -> Result<()> {
+        let mut a = Int32Builder::new(0);
+        a.append_value(7)?;
+
+        let values = Buffer::from(&[1i32, 2, 3, 4].to_byte_slice());
+        let validity = Buffer::from([0b0000_1011_u8]);
+        a.append_values_buffer(&values, 4, Some(&validity))?;
+        a.append_values_buffer(&Buffer::from(&[5i32].to_byte_slice()), 1, None)?;
+
+        let array = a.finish();
+        assert_eq!(
+            Int32Array::from(vec![Some(7), Some(1), Some(2), None, Some(4), Some(5)]),
+            array
+        );
+
+        let mut a = Int32Builder::new(0);
+        assert!(a.append_values_buffer(&values, 3, None).is_err());
+
+        let mut a = Int32Builder::new(0).non_nullable();
+        assert!(a.append_values_buffer(&values, 4, Some(&validity)).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_primitive_array_builder_i32() {
         let mut builder = Int32Array::builder(5);
@@ -2793,6 +3347,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_primitive_array_builder_append_option_slice() {
+        let mut builder = Int32Array::builder(3);
+        builder.append_option(Some(1)).unwrap();
+        builder.append_option(None).unwrap();
+        builder.append_option(Some(3)).unwrap();
+        let arr1 = builder.finish();
+
+        let mut builder = Int32Array::builder(0);
+        builder
+            .append_option_slice(&[Some(1), None, Some(3)])
+            .unwrap();
+        let arr2 = builder.finish();
+
+        assert_eq!(arr1.len(), arr2.len());
+        assert_eq!(arr1.null_count(), arr2.null_count());
+        for i in 0..3 {
+            assert_eq!(arr1.is_valid(i), arr2.is_valid(i));
+            if arr1.is_valid(i) {
+                assert_eq!(arr1.value(i), arr2.value(i));
+            }
+        }
+    }
+
+    #[test]
+    fn test_primitive_array_builder_statistics() {
+        let mut builder = Int32Builder::new(4).with_statistics();
+        builder.append_value(3).unwrap();
+        builder.append_value(1).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(4).unwrap();
+
+        let statistics = builder.statistics().unwrap();
+        assert_eq!(Some(1), statistics.min);
+        assert_eq!(Some(4), statistics.max);
+        assert_eq!(1, statistics.null_count);
+
+        builder.append_slice(&[-2, 10]).unwrap();
+        builder.append_option_slice(&[None, Some(5)]).unwrap();
+        let statistics = builder.statistics().unwrap();
+        assert_eq!(Some(-2), statistics.min);
+        assert_eq!(Some(10), statistics.max);
+        assert_eq!(2, statistics.null_count);
+
+        // finishing resets the statistics
+        builder.finish();
+        assert_eq!(ColumnStatistics::default(), builder.statistics().unwrap());
+
+        let builder = Int32Builder::new(4);
+        assert!(builder.statistics().is_none());
+    }
+
+    #[test]
+    fn test_primitive_array_builder_statistics_of_existing_values() {
+        let mut builder = Float64Builder::new(4);
+        builder.append_value(2.5).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-1.0).unwrap();
+        let builder = builder.with_statistics();
+
+        let statistics = builder.statistics().unwrap();
+        assert_eq!(Some(-1.0), statistics.min);
+        assert_eq!(Some(2.5), statistics.max);
+        assert_eq!(1, statistics.null_count);
+    }
+
     #[test]
     fn test_primitive_array_builder_finish() {
         let mut builder = Int32Builder::new(5);
@@ -2807,6 +3427,163 @@ mod tests {
         assert_eq!(0, builder.len());
     }
 
+    #[test]
+    fn test_primitive_array_builder_finish_all_valid_drops_bitmap() {
+        let mut builder = Int32Builder::new(5);
+        builder.append_value(1).unwrap();
+        builder.append_option(Some(2)).unwrap();
+        builder.append_values(&[3, 4], &[true, true]).unwrap();
+        let arr = builder.finish();
+        assert_eq!(4, arr.len());
+        assert_eq!(0, arr.null_count());
+        assert!(arr.data().null_buffer().is_none());
+        assert!((0..4).all(|i| !arr.is_null(i) && arr.is_valid(i)));
+
+        // a null in an earlier array does not carry over to the next one
+        builder.append_null().unwrap();
+        assert!(builder.finish().data().null_buffer().is_some());
+        builder.append_value(5).unwrap();
+        assert!(builder.finish().data().null_buffer().is_none());
+
+        let mut builder = BooleanBuilder::new(2);
+        builder.append_slice(&[true, false]).unwrap();
+        let arr = builder.finish();
+        assert!(arr.data().null_buffer().is_none());
+        assert!(!arr.is_null(1));
+    }
+
+    #[test]
+    fn test_primitive_array_builder_finish_cloned() {
+        let mut builder = Int32Builder::new(5);
+        builder.append_value(1).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(3).unwrap();
+        let arr = builder.finish_cloned();
+        assert_eq!(3, arr.len());
+        assert_eq!(1, arr.null_count());
+        assert_eq!(3, builder.len());
+
+        builder.append_slice(&[4, 5]).unwrap();
+        let arr = builder.finish();
+        assert_eq!(5, arr.len());
+        assert_eq!(1, arr.null_count());
+        assert_eq!(0, builder.len());
+        assert_eq!(1, arr.value(0));
+        assert!(arr.is_null(1));
+        assert_eq!(&[3, 4, 5], arr.value_slice(2, 3));
+    }
+
+    #[test]
+    fn test_primitive_array_builder_null_count() {
+        let mut builder = Int32Array::builder(4);
+        assert_eq!(0, builder.null_count());
+        builder.append_value(1).unwrap();
+        builder.append_null().unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(4).unwrap();
+        assert_eq!(2, builder.null_count());
+
+        // appending past the initial capacity keeps the count
+        builder.append_slice(&[0; 10]).unwrap();
+        builder.append_option(None).unwrap();
+        assert_eq!(3, builder.null_count());
+
+        let arr = builder.finish();
+        assert_eq!(3, arr.null_count());
+        assert_eq!(0, builder.null_count());
+
+        let mut builder = Int32Builder::new(2).non_nullable();
+        builder.append_value(1).unwrap();
+        assert_eq!(0, builder.null_count());
+    }
+
+    #[test]
+    fn test_primitive_array_builder_non_nullable() {
+        let mut builder = Int32Builder::new(5).non_nullable();
+        assert!(!builder.is_nullable());
+        builder.append_value(1).unwrap();
+        builder.append_slice(&[2, 3]).unwrap();
+        builder.append_option(Some(4)).unwrap();
+        assert!(builder.append_null().is_err());
+        assert!(builder.append_option(None).is_err());
+        assert!(builder.append_values(&[5], &[false]).is_err());
+        builder.append_values(&[5], &[true]).unwrap();
+        assert_eq!(5, builder.len());
+
+        let arr = builder.finish();
+        assert_eq!(5, arr.len());
+        assert_eq!(0, arr.null_count());
+        assert!(arr.data().null_buffer().is_none());
+        assert!(!arr.is_null(4));
+        assert_eq!(&[1, 2, 3, 4, 5], arr.value_slice(0, 5));
+
+        let nullable =
+            Int32Array::from(vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        assert!(nullable.data().null_buffer().is_some());
+        assert!(arr.get_array_memory_size() < nullable.get_array_memory_size());
+
+        // the builder can be reused after finishing
+        builder.append_value(6).unwrap();
+        let arr = builder.finish();
+        assert_eq!(1, arr.len());
+        assert!(arr.data().null_buffer().is_none());
+    }
+
+    #[test]
+    fn test_primitive_array_builder_non_nullable_append_data() {
+        let mut builder = Int32Builder::new(5).non_nullable();
+        let valid = Int32Array::from(vec![1, 2]);
+        builder.append_data(&[valid.data()]).unwrap();
+        let with_nulls = Int32Array::from(vec![Some(3), None]);
+        assert!(builder.append_data(&[with_nulls.data()]).is_err());
+
+        let arr = builder.finish();
+        assert_eq!(2, arr.len());
+        assert!(arr.data().null_buffer().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot make a builder containing nulls non-nullable")]
+    fn test_primitive_array_builder_non_nullable_with_nulls() {
+        let mut builder = Int32Builder::new(5);
+        builder.append_null().unwrap();
+        builder.non_nullable();
+    }
+
+    #[test]
+    fn test_primitive_array_builder_set_validity_from() {
+        let mut builder = Int32Builder::new(5);
+        builder.append_slice(&[1, 2, 3]).unwrap();
+        builder
+            .set_validity_from(&Buffer::from([0b1111_1101_u8]), 3)
+            .unwrap();
+        builder.append_value(4).unwrap();
+        builder.append_null().unwrap();
+
+        let arr = builder.finish();
+        assert_eq!(5, arr.len());
+        assert_eq!(2, arr.null_count());
+        assert_eq!(
+            vec![Some(1), None, Some(3), Some(4), None],
+            arr.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_primitive_array_builder_set_validity_from_invalid() {
+        let mut builder = Int32Builder::new(5);
+        builder.append_slice(&[1, 2, 3]).unwrap();
+        let validity = Buffer::from([0b101_u8]);
+        assert!(builder.set_validity_from(&validity, 2).is_err());
+        assert!(builder
+            .set_validity_from(&Buffer::from(&[] as &[u8]), 3)
+            .is_err());
+
+        let mut builder = Int32Builder::new(5).non_nullable();
+        builder.append_slice(&[1, 2, 3]).unwrap();
+        assert!(builder.set_validity_from(&validity, 3).is_err());
+    }
+
     #[test]
     fn test_list_array_builder() {
         let values_builder = Int32Builder::new(10);
@@ -2885,6 +3662,89 @@ mod tests {
         }
     }
 
+    /// A user-defined builder that takes temperatures in Fahrenheit and stores them
+    /// in Celsius
+    #[derive(Debug)]
+    struct CelsiusBuilder {
+        values: Float64Builder,
+    }
+
+    impl CelsiusBuilder {
+        fn append_fahrenheit(&mut self, v: f64) -> Result<()> {
+            self.values.append_value((v - 32.0) * 5.0 / 9.0)
+        }
+    }
+
+    impl ArrayBuilder for CelsiusBuilder {
+        fn len(&self) -> usize {
+            self.values.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.values.is_empty()
+        }
+
+        fn append_data(&mut self, data: &[ArrayDataRef]) -> Result<()> {
+            self.values.append_data(data)
+        }
+
+        fn data_type(&self) -> DataType {
+            DataType::Float64
+        }
+
+        fn finish(&mut self) -> ArrayRef {
+            Arc::new(self.values.finish())
+        }
+
+        fn as_any(&self) -> &Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut Any {
+            self
+        }
+
+        fn into_box_any(self: Box<Self>) -> Box<Any> {
+            self
+        }
+    }
+
+    #[test]
+    fn test_list_array_builder_custom_values_builder() {
+        let values_builder = CelsiusBuilder {
+            values: Float64Builder::new(3),
+        };
+        let mut builder = ListBuilder::new(values_builder);
+
+        // [32, 212], null, [-40]
+        builder.values().append_fahrenheit(32.0).unwrap();
+        builder.values().append_fahrenheit(212.0).unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.values().append_fahrenheit(-40.0).unwrap();
+        builder.append(true).unwrap();
+
+        let list_array = builder.finish();
+        assert_eq!(
+            &DataType::List(Box::new(DataType::Float64)),
+            list_array.data_type()
+        );
+        assert_eq!(3, list_array.len());
+        assert_eq!(1, list_array.null_count());
+        assert_eq!(2, list_array.value_length(0));
+        assert_eq!(0, list_array.value_length(1));
+
+        let values = list_array.values();
+        let values = values.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(&[0.0, 100.0, -40.0], values.value_slice(0, 3));
+
+        // the custom builder also composes through a boxed trait object
+        let mut boxed: Box<ArrayBuilder> = Box::new(ListBuilder::new(CelsiusBuilder {
+            values: Float64Builder::new(0),
+        }));
+        assert_eq!(0, boxed.finish().len());
+    }
+
     #[test]
     fn test_list_array_builder_nulls() {
         let values_builder = Int32Builder::new(10);
@@ -3022,6 +3882,88 @@ mod tests {
         assert_eq!(0, builder.len());
     }
 
+    #[test]
+    fn test_list_list_struct_array_builder() {
+        let fields = vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ];
+        let struct_builder = StructBuilder::new(
+            fields.clone(),
+            vec![
+                Box::new(Int32Builder::new(3)) as Box<ArrayBuilder>,
+                Box::new(StringBuilder::new(3)) as Box<ArrayBuilder>,
+            ],
+        );
+        let mut builder = ListBuilder::new(ListBuilder::new(struct_builder));
+
+        fn append_struct(builder: &mut StructBuilder, a: i32, b: &str) {
+            builder
+                .field_builder::<Int32Builder>(0)
+                .unwrap()
+                .append_value(a)
+                .unwrap();
+            builder
+                .field_builder::<StringBuilder>(1)
+                .unwrap()
+                .append_value(b)
+                .unwrap();
+            builder.append(true).unwrap();
+        }
+
+        // [[{a: 1, b: "x"}, {a: 2, b: "y"}], [{a: 3, b: "z"}]]
+        append_struct(builder.values().values(), 1, "x");
+        append_struct(builder.values().values(), 2, "y");
+        builder.values().append(true).unwrap();
+        append_struct(builder.values().values(), 3, "z");
+        builder.values().append(true).unwrap();
+        builder.append(true).unwrap();
+        // []
+        builder.append(true).unwrap();
+        // null
+        builder.append(false).unwrap();
+
+        let list_array = builder.finish();
+        assert_eq!(
+            &DataType::List(Box::new(DataType::List(Box::new(DataType::Struct(fields))))),
+            list_array.data_type()
+        );
+        assert_eq!(3, list_array.len());
+        assert_eq!(1, list_array.null_count());
+        assert_eq!(
+            vec![0, 2, 2, 2],
+            (0..=3)
+                .map(|i| list_array.value_offset(i))
+                .collect::<Vec<i32>>()
+        );
+
+        let inner = list_array.values();
+        let inner = inner.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(2, inner.len());
+        assert_eq!(
+            vec![0, 2, 3],
+            (0..=2).map(|i| inner.value_offset(i)).collect::<Vec<i32>>()
+        );
+
+        let structs = inner.values();
+        let structs = structs.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(3, structs.len());
+        let a = structs
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&[1, 2, 3], a.value_slice(0, 3));
+        let b = structs
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("x", b.value(0));
+        assert_eq!("y", b.value(1));
+        assert_eq!("z", b.value(2));
+    }
+
     #[test]
     fn test_list_list_array_builder() {
         let primitive_builder = Int32Builder::new(10);
@@ -3174,6 +4116,39 @@ mod tests {
         assert_eq!(5, fixed_size_binary_array.value_length());
     }
 
+    #[test]
+    fn test_fixed_size_binary_builder_uuids() {
+        let data_type = DataType::FixedSizeBinary(16);
+        assert_eq!(
+            serde_json::json!({"name": "fixedsizebinary", "byteWidth": 16}),
+            data_type.to_json()
+        );
+        assert_eq!(data_type, DataType::from(&data_type.to_json()).unwrap());
+
+        let uuid1: [u8; 16] = [
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e,
+            0x5f, 0xe0, 0xc8,
+        ];
+        let uuid2: [u8; 16] = [
+            0x12, 0x3e, 0x45, 0x67, 0xe8, 0x9b, 0x12, 0xd3, 0xa4, 0x56, 0x42, 0x66, 0x14,
+            0x17, 0x40, 0x00,
+        ];
+        let mut builder = FixedSizeBinaryBuilder::new(32, 16);
+        builder.append_value(&uuid1).unwrap();
+        builder.append_value(&uuid2).unwrap();
+        let err = builder.append_value(&uuid1[..8]).unwrap_err();
+        assert!(format!("{}", err).contains("Byte slice of length 8"));
+
+        let array = builder.finish();
+        assert_eq!(&data_type, array.data_type());
+        assert_eq!(2, array.len());
+        assert_eq!(32, array.value_data().len());
+        // fixed size binary arrays have no offsets buffer
+        assert_eq!(1, array.data().buffers().len());
+        assert_eq!(&uuid1, array.value(0));
+        assert_eq!(&uuid2, array.value(1));
+    }
+
     #[test]
     fn test_string_array_builder_finish() {
         let mut builder = StringBuilder::new(10);
@@ -3358,6 +4333,65 @@ mod tests {
         assert_eq!(0, builder.len());
     }
 
+    #[test]
+    fn test_struct_array_builder_with_list_field() {
+        let tags_type = DataType::List(Box::new(DataType::Utf8));
+        let fields = vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("tags", tags_type.clone(), true),
+        ];
+        let field_builders = vec![
+            Box::new(Int32Builder::new(2)) as Box<ArrayBuilder>,
+            Box::new(ListBuilder::new(StringBuilder::new(3))) as Box<ArrayBuilder>,
+        ];
+        let mut builder = StructBuilder::new(fields, field_builders);
+
+        // {id: 1, tags: ["a", "b"]}
+        builder
+            .field_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(1)
+            .unwrap();
+        let tags_builder = builder
+            .field_builder::<ListBuilder<StringBuilder>>(1)
+            .expect("builder at field 1 should be a list builder");
+        tags_builder.values().append_value("a").unwrap();
+        tags_builder.values().append_value("b").unwrap();
+        tags_builder.append(true).unwrap();
+        builder.append(true).unwrap();
+
+        // {id: 2, tags: null}
+        builder
+            .field_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(2)
+            .unwrap();
+        builder
+            .field_builder::<ListBuilder<StringBuilder>>(1)
+            .unwrap()
+            .append(false)
+            .unwrap();
+        builder.append(true).unwrap();
+
+        let arr = builder.finish();
+        assert_eq!(2, arr.len());
+        assert_eq!(0, arr.null_count());
+
+        let ids = arr.column(0).as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&[1, 2], ids.value_slice(0, 2));
+
+        assert_eq!(&tags_type, arr.column(1).data_type());
+        let tags = arr.column(1).as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(2, tags.len());
+        assert_eq!(1, tags.null_count());
+        assert!(tags.is_null(1));
+        let first = tags.value(0);
+        let first = first.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(2, first.len());
+        assert_eq!("a", first.value(0));
+        assert_eq!("b", first.value(1));
+    }
+
     #[test]
     fn test_struct_array_builder_from_schema() {
         let mut fields = Vec::new();
@@ -23,7 +23,9 @@ use std::sync::Arc;
 
 use crate::bitmap::Bitmap;
 use crate::buffer::Buffer;
-use crate::datatypes::DataType;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::memory;
 use crate::util::bit_util;
 
 /// An generic representation of Arrow array data which encapsulates common attributes and
@@ -106,6 +108,12 @@ impl ArrayData {
         &self.data_type
     }
 
+    /// Consumes this array data, returning its buffers and the buffer of its null
+    /// bitmap, if any
+    pub(crate) fn into_buffers(self) -> (Vec<Buffer>, Option<Buffer>) {
+        (self.buffers, self.null_bitmap.map(|bitmap| bitmap.bits))
+    }
+
     /// Returns a slice of buffers for this array data
     pub fn buffers(&self) -> &[Buffer] {
         &self.buffers[..]
@@ -279,6 +287,190 @@ impl ArrayDataBuilder {
         );
         Arc::new(data)
     }
+
+    /// Builds the `ArrayData`, first checking that the buffers are consistent with the
+    /// data type and length.
+    ///
+    /// This validates the number of buffers, that the null bitmap and the fixed-width
+    /// values buffers are long enough to hold `offset + len` slots, and that the offsets
+    /// of variable-sized binary and string arrays stay within their values buffer.
+    /// Use `build` when the buffers are already known to be valid.
+    pub fn try_build(self) -> Result<ArrayDataRef> {
+        self.validate()?;
+        Ok(self.build())
+    }
+
+    fn validate(&self) -> Result<()> {
+        let slots = self.offset + self.len;
+
+        if let Some(ref bitmap) = self.null_bit_buffer {
+            let needed = bit_util::ceil(slots, 8);
+            if bitmap.len() < needed {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Null bitmap of {} bytes is too short for {} slots, expected at least {} bytes",
+                    bitmap.len(),
+                    slots,
+                    needed
+                )));
+            }
+        }
+        if let Some(null_count) = self.null_count {
+            if null_count > self.len {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Null count {} exceeds the array length {}",
+                    null_count, self.len
+                )));
+            }
+        }
+
+        if self.data_type == DataType::Utf8View {
+            // the views are followed by any number of data buffers
+            if self.buffers.is_empty() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Expected at least 1 buffer for an array of type {:?}, but got 0",
+                    self.data_type
+                )));
+            }
+            return self.validate_buffer_len(0, slots * 16);
+        }
+
+        if let DataType::Union(_) = self.data_type {
+            // sparse unions have a type ids buffer, dense unions also have offsets
+            if self.buffers.is_empty() || self.buffers.len() > 2 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Expected 1 or 2 buffers for an array of type {:?}, but got {}",
+                    self.data_type,
+                    self.buffers.len()
+                )));
+            }
+            self.validate_buffer_len(0, slots)?;
+            if self.buffers.len() == 2 {
+                self.validate_buffer_len(1, slots * mem::size_of::<i32>())?;
+            }
+            return Ok(());
+        }
+
+        let expected_buffers = match self.data_type {
+            DataType::Null | DataType::Struct(_) | DataType::FixedSizeList(_, _) => 0,
+            DataType::Binary
+            | DataType::LargeBinary
+            | DataType::Utf8
+            | DataType::LargeUtf8 => 2,
+            _ => 1,
+        };
+        if self.buffers.len() != expected_buffers {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected {} buffers for an array of type {:?}, but got {}",
+                expected_buffers,
+                self.data_type,
+                self.buffers.len()
+            )));
+        }
+
+        match self.data_type {
+            DataType::Boolean => self.validate_buffer_len(0, bit_util::ceil(slots, 8))?,
+            DataType::Binary | DataType::Utf8 | DataType::List(_) => {
+                self.validate_offsets::<i32>()?
+            }
+            DataType::LargeBinary | DataType::LargeUtf8 | DataType::LargeList(_) => {
+                self.validate_offsets::<i64>()?
+            }
+            _ => {
+                if let Some(width) = fixed_byte_width(&self.data_type) {
+                    self.validate_buffer_len(0, slots * width)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the buffer at index `i` holds at least `needed` bytes.
+    fn validate_buffer_len(&self, i: usize, needed: usize) -> Result<()> {
+        let len = self.buffers[i].len();
+        if len < needed {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Buffer {} of an array of type {:?} holds {} bytes, expected at least {}",
+                i, self.data_type, len, needed
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that the offsets buffer covers all slots and, for binary and string
+    /// arrays, that the last offset does not point past the values buffer.
+    fn validate_offsets<T>(&self) -> Result<()>
+    where
+        T: Copy + Into<i64>,
+    {
+        if self.len == 0 {
+            return Ok(());
+        }
+        let slots = self.offset + self.len;
+        let width = mem::size_of::<T>();
+        self.validate_buffer_len(0, (slots + 1) * width)?;
+        if !memory::is_aligned(self.buffers[0].raw_data(), mem::align_of::<T>()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Offsets buffer of an array of type {:?} is not aligned to {} bytes",
+                self.data_type,
+                mem::align_of::<T>()
+            )));
+        }
+
+        let offsets = unsafe {
+            std::slice::from_raw_parts(self.buffers[0].raw_data() as *const T, slots + 1)
+        };
+        let first: i64 = offsets[self.offset].into();
+        let last: i64 = offsets[slots].into();
+        if first < 0 || last < first {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid offsets {}..{} for an array of type {:?}",
+                first, last, self.data_type
+            )));
+        }
+        let values_len = if self.buffers.len() > 1 {
+            self.buffers[1].len()
+        } else if let Some(child) = self.child_data.first() {
+            child.len()
+        } else {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "An array of type {:?} requires child data",
+                self.data_type
+            )));
+        };
+        if last as usize > values_len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Offset {} is out of bounds for values of length {}",
+                last, values_len
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the byte width of the values of a fixed-width type, or `None` for types
+/// whose values are not stored in a single fixed-width buffer.
+fn fixed_byte_width(data_type: &DataType) -> Option<usize> {
+    match data_type {
+        DataType::Int8 | DataType::UInt8 => Some(1),
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => Some(2),
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => Some(4),
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => Some(8),
+        DataType::FixedSizeBinary(size) => Some(*size as usize),
+        DataType::Dictionary(key_type, _) => fixed_byte_width(key_type),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -288,6 +480,7 @@ mod tests {
     use std::sync::Arc;
 
     use crate::buffer::Buffer;
+    use crate::datatypes::{Field, ToByteSlice};
     use crate::util::bit_util;
 
     #[test]
@@ -356,6 +549,120 @@ mod tests {
         assert_eq!(10, arr_data.null_count());
     }
 
+    #[test]
+    fn test_try_build() {
+        let arr_data = ArrayData::builder(DataType::Int32)
+            .len(4)
+            .add_buffer(Buffer::from(&[1i32, 2, 3, 4].to_byte_slice()))
+            .try_build()
+            .unwrap();
+        assert_eq!(4, arr_data.len());
+    }
+
+    #[test]
+    fn test_try_build_short_values_buffer() {
+        let result = ArrayData::builder(DataType::Int32)
+            .len(4)
+            .add_buffer(Buffer::from(&[1i32, 2, 3].to_byte_slice()))
+            .try_build();
+        assert!(result.is_err());
+
+        // the offset also counts towards the required length
+        let result = ArrayData::builder(DataType::Int64)
+            .len(2)
+            .offset(1)
+            .add_buffer(Buffer::from(&[1i64, 2].to_byte_slice()))
+            .try_build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_build_invalid_buffers() {
+        // missing values buffer
+        let result = ArrayData::builder(DataType::Utf8)
+            .len(1)
+            .add_buffer(Buffer::from(&[0i32, 1].to_byte_slice()))
+            .try_build();
+        assert!(result.is_err());
+
+        // null bitmap too short
+        let result = ArrayData::builder(DataType::Int8)
+            .len(9)
+            .add_buffer(Buffer::from(&[0u8; 9][..]))
+            .null_bit_buffer(Buffer::from([0b1111_1111u8]))
+            .try_build();
+        assert!(result.is_err());
+
+        // last offset past the end of the values
+        let result = ArrayData::builder(DataType::Utf8)
+            .len(2)
+            .add_buffer(Buffer::from(&[0i32, 2, 5].to_byte_slice()))
+            .add_buffer(Buffer::from(&b"abcd"[..]))
+            .try_build();
+        assert!(result.is_err());
+
+        let arr_data = ArrayData::builder(DataType::Utf8)
+            .len(2)
+            .add_buffer(Buffer::from(&[0i32, 2, 4].to_byte_slice()))
+            .add_buffer(Buffer::from(&b"abcd"[..]))
+            .try_build()
+            .unwrap();
+        assert_eq!(2, arr_data.len());
+
+        // offsets that are not aligned to 4 bytes
+        let offsets = Buffer::from(&[0u8; 13][..]).slice(1);
+        let result = ArrayData::builder(DataType::Utf8)
+            .len(2)
+            .add_buffer(offsets)
+            .add_buffer(Buffer::from(&b"abcd"[..]))
+            .try_build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_build_union() {
+        let data_type = DataType::Union(vec![Field::new("a", DataType::Int32, false)]);
+        let child = ArrayData::builder(DataType::Int32)
+            .len(2)
+            .add_buffer(Buffer::from(&[1i32, 2].to_byte_slice()))
+            .build();
+
+        // sparse
+        let arr_data = ArrayData::builder(data_type.clone())
+            .len(2)
+            .add_buffer(Buffer::from(&[0i8, 0].to_byte_slice()))
+            .add_child_data(child.clone())
+            .try_build()
+            .unwrap();
+        assert_eq!(2, arr_data.len());
+
+        // dense
+        let arr_data = ArrayData::builder(data_type.clone())
+            .len(2)
+            .add_buffer(Buffer::from(&[0i8, 0].to_byte_slice()))
+            .add_buffer(Buffer::from(&[0i32, 1].to_byte_slice()))
+            .add_child_data(child.clone())
+            .try_build()
+            .unwrap();
+        assert_eq!(2, arr_data.len());
+
+        // missing type ids
+        let result = ArrayData::builder(data_type.clone())
+            .len(2)
+            .add_child_data(child.clone())
+            .try_build();
+        assert!(result.is_err());
+
+        // dense offsets too short
+        let result = ArrayData::builder(data_type)
+            .len(2)
+            .add_buffer(Buffer::from(&[0i8, 0].to_byte_slice()))
+            .add_buffer(Buffer::from(&[0i32].to_byte_slice()))
+            .add_child_data(child)
+            .try_build();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_null_buffer_ref() {
         let mut bit_v: [u8; 2] = [0; 2];
@@ -156,6 +156,12 @@ impl PartialEq for FixedSizeBinaryArray {
     }
 }
 
+impl PartialEq for StringViewArray {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+
 impl<OffsetSize: OffsetSizeTrait> ArrayEqual for GenericListArray<OffsetSize> {
     fn equals(&self, other: &dyn Array) -> bool {
         if !base_equal(&self.data(), &other.data()) {
@@ -779,6 +785,33 @@ impl ArrayEqual for NullArray {
     }
 }
 
+impl ArrayEqual for StringViewArray {
+    fn equals(&self, other: &dyn Array) -> bool {
+        if !base_equal(&self.data(), &other.data()) {
+            return false;
+        }
+        self.range_equals(other, 0, self.len(), 0)
+    }
+
+    fn range_equals(
+        &self,
+        other: &dyn Array,
+        start_idx: usize,
+        end_idx: usize,
+        other_start_idx: usize,
+    ) -> bool {
+        assert!(other_start_idx + (end_idx - start_idx) <= other.len());
+        let other = other.as_any().downcast_ref::<StringViewArray>().unwrap();
+
+        // views of equal strings may differ in where the strings are stored, so
+        // compare the strings themselves
+        (start_idx..end_idx).zip(other_start_idx..).all(|(i, j)| {
+            let is_null = self.is_null(i);
+            is_null == other.is_null(j) && (is_null || self.value(i) == other.value(j))
+        })
+    }
+}
+
 // Compare if the common basic fields between the two arrays are equal
 fn base_equal(this: &ArrayDataRef, other: &ArrayDataRef) -> bool {
     if this.data_type() != other.data_type() {
@@ -1171,6 +1204,20 @@ impl PartialEq<Value> for NullArray {
     }
 }
 
+impl JsonEqual for StringViewArray {
+    fn equals_json(&self, json: &[&Value]) -> bool {
+        if self.len() != json.len() {
+            return false;
+        }
+
+        (0..self.len()).all(|i| match json[i] {
+            JString(s) => self.is_valid(i) && s.as_str() == self.value(i),
+            JNull => self.is_null(i),
+            _ => false,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
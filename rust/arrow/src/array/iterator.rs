@@ -17,7 +17,9 @@
 
 use crate::datatypes::ArrowPrimitiveType;
 
-use super::{Array, PrimitiveArray, PrimitiveArrayOps};
+use super::{
+    Array, GenericStringArray, PrimitiveArray, PrimitiveArrayOps, StringOffsetSizeTrait,
+};
 
 /// an iterator that returns Some(T) or None, that can be used on any non-boolean PrimitiveArray
 #[derive(Debug)]
@@ -62,11 +64,60 @@ impl<'a, T: ArrowPrimitiveType> std::iter::Iterator for PrimitiveIter<'a, T> {
 /// all arrays have known size.
 impl<'a, T: ArrowPrimitiveType> std::iter::ExactSizeIterator for PrimitiveIter<'a, T> {}
 
+/// an iterator that returns `Some(&str)` or `None`, for string arrays
+#[derive(Debug)]
+pub struct GenericStringIter<'a, T>
+where
+    T: StringOffsetSizeTrait,
+{
+    array: &'a GenericStringArray<T>,
+    i: usize,
+    len: usize,
+}
+
+impl<'a, T: StringOffsetSizeTrait> GenericStringIter<'a, T> {
+    /// create a new iterator
+    pub fn new(array: &'a GenericStringArray<T>) -> Self {
+        GenericStringIter::<T> {
+            array,
+            i: 0,
+            len: array.len(),
+        }
+    }
+}
+
+impl<'a, T: StringOffsetSizeTrait> std::iter::Iterator for GenericStringIter<'a, T> {
+    type Item = Option<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.i;
+        if i >= self.len {
+            None
+        } else if self.array.is_null(i) {
+            self.i += 1;
+            Some(None)
+        } else {
+            self.i += 1;
+            Some(Some(self.array.value(i)))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len - self.i, Some(self.len - self.i))
+    }
+}
+
+/// all arrays have known size.
+impl<'a, T: StringOffsetSizeTrait> std::iter::ExactSizeIterator
+    for GenericStringIter<'a, T>
+{
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use crate::array::{ArrayRef, Int32Array};
+    use crate::array::{ArrayRef, Int32Array, LargeStringArray, StringArray};
 
     #[test]
     fn test_primitive_array_iter_round_trip() {
@@ -82,4 +133,28 @@ mod tests {
         let expected = Int32Array::from(vec![Some(1), None, Some(3), None, Some(5)]);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_string_array_iter() {
+        let array = StringArray::from(vec!["a", "bb", "ccc"]);
+        for (i, expected) in ["a", "bb", "ccc"].iter().enumerate() {
+            assert_eq!(*expected, array.value(i));
+            assert_eq!(expected.len() as i32, array.value_length(i));
+        }
+
+        let values: Vec<Option<&str>> = array.iter().collect();
+        assert_eq!(vec![Some("a"), Some("bb"), Some("ccc")], values);
+
+        let array = LargeStringArray::from(vec![Some("a"), None, Some("ccc")]);
+        let mut iter = array.iter();
+        assert_eq!(3, iter.len());
+        assert_eq!(Some(Some("a")), iter.next());
+        assert_eq!(Some(None), iter.next());
+        assert_eq!(1, iter.len());
+        assert_eq!(Some(Some("ccc")), iter.next());
+        assert_eq!(None, iter.next());
+
+        let lengths: Vec<usize> = (&array).into_iter().flatten().map(str::len).collect();
+        assert_eq!(vec![1, 3], lengths);
+    }
 }
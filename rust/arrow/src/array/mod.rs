@@ -90,6 +90,7 @@ mod equal;
 mod iterator;
 mod null;
 mod ord;
+mod string_view;
 mod union;
 
 use crate::datatypes::*;
@@ -114,6 +115,7 @@ pub use self::array::PrimitiveArray;
 pub use self::array::StringArray;
 pub use self::array::StructArray;
 pub use self::null::NullArray;
+pub use self::string_view::StringViewArray;
 pub use self::union::UnionArray;
 
 pub use self::array::make_array;
@@ -199,6 +201,7 @@ pub type DurationNanosecondBufferBuilder = BufferBuilder<DurationNanosecondType>
 
 pub use self::builder::ArrayBuilder;
 pub use self::builder::BinaryBuilder;
+pub use self::builder::ColumnStatistics;
 pub use self::builder::FixedSizeBinaryBuilder;
 pub use self::builder::FixedSizeListBuilder;
 pub use self::builder::LargeBinaryBuilder;
@@ -210,6 +213,7 @@ pub use self::builder::PrimitiveDictionaryBuilder;
 pub use self::builder::StringBuilder;
 pub use self::builder::StringDictionaryBuilder;
 pub use self::builder::StructBuilder;
+pub use self::string_view::StringViewBuilder;
 pub use self::union::UnionBuilder;
 
 pub type BooleanBuilder = PrimitiveBuilder<BooleanType>;
@@ -85,6 +85,11 @@ impl Array for NullArray {
         self.data_ref().len()
     }
 
+    /// A `NullArray` has no validity bitmap, all of its elements are null.
+    fn logical_null_count(&self) -> usize {
+        self.data_ref().len()
+    }
+
     /// Returns the total number of bytes of memory occupied by the buffers owned by this [NullArray].
     fn get_buffer_memory_size(&self) -> usize {
         self.data.get_buffer_memory_size()
@@ -127,6 +132,7 @@ mod tests {
 
         assert_eq!(null_arr.len(), 32);
         assert_eq!(null_arr.null_count(), 32);
+        assert_eq!(null_arr.logical_null_count(), 32);
         assert_eq!(null_arr.is_valid(0), false);
 
         assert_eq!(0, null_arr.get_buffer_memory_size());
@@ -0,0 +1,424 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Contains the `StringViewArray` type and its builder.
+//!
+//! A `StringViewArray` stores a 16-byte view per slot. The first 4 bytes of a view
+//! hold the length of the string in bytes. Strings of up to 12 bytes are stored inline
+//! in the remaining 12 bytes. Longer strings store their first 4 bytes, the index of
+//! the data buffer that holds the string and the offset of the string in that buffer.
+//!
+//! # Example: Create an array
+//!
+//! ```
+//! use arrow::array::{Array, StringViewBuilder};
+//!
+//! # fn main() -> arrow::error::Result<()> {
+//! let mut builder = StringViewBuilder::new(2);
+//! builder.append_value("hi")?;
+//! builder.append_null()?;
+//! let array = builder.finish();
+//!
+//! assert_eq!(array.len(), 2);
+//! assert_eq!(array.value(0), "hi");
+//! assert!(array.is_null(1));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::any::Any;
+use std::convert::TryInto;
+use std::fmt;
+use std::io::Write;
+use std::mem;
+use std::sync::Arc;
+
+use crate::array::{
+    Array, ArrayBuilder, ArrayData, ArrayDataRef, ArrayRef, BooleanBufferBuilder,
+    BufferBuilderTrait,
+};
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::util::bit_util;
+
+/// The size of a view in bytes
+const VIEW_LEN: usize = 16;
+/// The maximum length of a string that is stored inline in its view
+const MAX_INLINE_LEN: usize = 12;
+/// The default size of the data buffers that hold strings longer than `MAX_INLINE_LEN`
+const DEFAULT_BLOCK_SIZE: usize = 8 * 1024;
+
+/// An array of UTF-8 strings stored as views, see [`DataType::Utf8View`].
+///
+/// The first buffer of the array data holds the views, the remaining buffers hold the
+/// strings that are too long to be inlined.
+pub struct StringViewArray {
+    data: ArrayDataRef,
+}
+
+impl StringViewArray {
+    /// Returns the view of the element at index `i`.
+    #[inline]
+    fn view(&self, i: usize) -> &[u8] {
+        let start = (self.data.offset() + i) * VIEW_LEN;
+        &self.data.buffers()[0].data()[start..start + VIEW_LEN]
+    }
+
+    /// Returns the length in bytes of the element at index `i`.
+    #[inline]
+    pub fn value_length(&self, i: usize) -> usize {
+        u32::from_le_bytes(self.view(i)[0..4].try_into().unwrap()) as usize
+    }
+
+    /// Returns the element at index `i` as `&str`.
+    pub fn value(&self, i: usize) -> &str {
+        assert!(i < self.len(), "StringViewArray out of bounds access");
+        let view = self.view(i);
+        let len = self.value_length(i);
+        let bytes = if len <= MAX_INLINE_LEN {
+            &view[4..4 + len]
+        } else {
+            let buffer_index =
+                u32::from_le_bytes(view[8..12].try_into().unwrap()) as usize;
+            let offset = u32::from_le_bytes(view[12..16].try_into().unwrap()) as usize;
+            &self.data.buffers()[buffer_index + 1].data()[offset..offset + len]
+        };
+        // the builder only accepts `&str`, so the bytes are valid UTF-8
+        unsafe { std::str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Returns the buffers that hold the strings longer than 12 bytes.
+    pub fn data_buffers(&self) -> &[Buffer] {
+        &self.data.buffers()[1..]
+    }
+}
+
+impl Array for StringViewArray {
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn data(&self) -> ArrayDataRef {
+        self.data.clone()
+    }
+
+    fn data_ref(&self) -> &ArrayDataRef {
+        &self.data
+    }
+
+    /// Returns the total number of bytes of memory occupied by the buffers owned by this [StringViewArray].
+    fn get_buffer_memory_size(&self) -> usize {
+        self.data.get_buffer_memory_size()
+    }
+
+    /// Returns the total number of bytes of memory occupied physically by this [StringViewArray].
+    fn get_array_memory_size(&self) -> usize {
+        self.data.get_array_memory_size() + mem::size_of_val(self)
+    }
+}
+
+impl From<ArrayDataRef> for StringViewArray {
+    fn from(data: ArrayDataRef) -> Self {
+        assert_eq!(
+            data.data_type(),
+            &DataType::Utf8View,
+            "StringViewArray expects DataType::Utf8View"
+        );
+        assert!(
+            !data.buffers().is_empty(),
+            "StringViewArray data should contain at least 1 buffer (the views)"
+        );
+        assert!(
+            data.buffers()[0].len() >= (data.offset() + data.len()) * VIEW_LEN,
+            "StringViewArray views buffer is too short for the array length"
+        );
+        Self { data }
+    }
+}
+
+impl<'a> From<Vec<Option<&'a str>>> for StringViewArray {
+    fn from(v: Vec<Option<&'a str>>) -> Self {
+        let mut builder = StringViewBuilder::new(v.len());
+        for value in v {
+            builder.append_option(value).unwrap();
+        }
+        builder.finish()
+    }
+}
+
+impl fmt::Debug for StringViewArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "StringViewArray\n[\n")?;
+        for i in 0..self.len() {
+            if self.is_null(i) {
+                writeln!(f, "  null,")?;
+            } else {
+                writeln!(f, "  {:?},", self.value(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/// Builder for [`StringViewArray`].
+///
+/// Strings of up to 12 bytes are inlined into their view. Longer strings are copied
+/// into data buffers, a new one being started once the current one is full.
+#[derive(Debug)]
+pub struct StringViewBuilder {
+    views: MutableBuffer,
+    bitmap_builder: BooleanBufferBuilder,
+    in_progress: Vec<u8>,
+    completed: Vec<Buffer>,
+    block_size: usize,
+    len: usize,
+}
+
+impl StringViewBuilder {
+    /// Creates a new `StringViewBuilder` with room for `capacity` views.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_block_size(capacity, DEFAULT_BLOCK_SIZE)
+    }
+
+    /// Creates a new `StringViewBuilder` whose data buffers hold `block_size` bytes,
+    /// unless a single string is longer than that.
+    pub fn with_block_size(capacity: usize, block_size: usize) -> Self {
+        Self {
+            views: MutableBuffer::new(capacity * VIEW_LEN),
+            bitmap_builder: BooleanBufferBuilder::new(capacity),
+            in_progress: Vec::new(),
+            completed: Vec::new(),
+            block_size,
+            len: 0,
+        }
+    }
+
+    /// Appends a string into the builder
+    pub fn append_value(&mut self, value: &str) -> Result<()> {
+        let bytes = value.as_bytes();
+        if bytes.len() > u32::MAX as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "String of {} bytes is too long for a string view",
+                bytes.len()
+            )));
+        }
+        let mut view = [0u8; VIEW_LEN];
+        view[0..4].copy_from_slice(&(bytes.len() as u32).to_le_bytes());
+        if bytes.len() <= MAX_INLINE_LEN {
+            view[4..4 + bytes.len()].copy_from_slice(bytes);
+        } else {
+            if !self.in_progress.is_empty()
+                && self.in_progress.len() + bytes.len() > self.block_size
+            {
+                self.flush_in_progress();
+            }
+            if self.in_progress.len() + bytes.len() > u32::MAX as usize {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "String of {} bytes does not fit into a data buffer",
+                    bytes.len()
+                )));
+            }
+            let buffer_index = self.completed.len() as u32;
+            let offset = self.in_progress.len() as u32;
+            self.in_progress.extend_from_slice(bytes);
+            view[4..8].copy_from_slice(&bytes[0..4]);
+            view[8..12].copy_from_slice(&buffer_index.to_le_bytes());
+            view[12..16].copy_from_slice(&offset.to_le_bytes());
+        }
+        self.append_view(&view)?;
+        self.bitmap_builder.append(true)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends a null slot into the builder
+    pub fn append_null(&mut self) -> Result<()> {
+        self.append_view(&[0u8; VIEW_LEN])?;
+        self.bitmap_builder.append(false)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends an `Option<&str>` into the builder
+    pub fn append_option(&mut self, value: Option<&str>) -> Result<()> {
+        match value {
+            Some(value) => self.append_value(value),
+            None => self.append_null(),
+        }
+    }
+
+    /// Builds the `StringViewArray` and reset this builder.
+    pub fn finish(&mut self) -> StringViewArray {
+        if !self.in_progress.is_empty() {
+            self.flush_in_progress();
+        }
+        let len = mem::replace(&mut self.len, 0);
+        let views = mem::replace(&mut self.views, MutableBuffer::new(0));
+        let null_bit_buffer = self.bitmap_builder.finish();
+        let null_count =
+            len - bit_util::count_set_bits_offset(null_bit_buffer.data(), 0, len);
+
+        let mut builder = ArrayData::builder(DataType::Utf8View)
+            .len(len)
+            .add_buffer(views.freeze());
+        for buffer in self.completed.drain(..) {
+            builder = builder.add_buffer(buffer);
+        }
+        if null_count > 0 {
+            builder = builder.null_bit_buffer(null_bit_buffer);
+        }
+        StringViewArray::from(builder.build())
+    }
+
+    fn append_view(&mut self, view: &[u8; VIEW_LEN]) -> Result<()> {
+        self.views.reserve(self.views.len() + VIEW_LEN)?;
+        self.views.write_all(view)?;
+        Ok(())
+    }
+
+    fn flush_in_progress(&mut self) {
+        let in_progress = mem::replace(&mut self.in_progress, Vec::new());
+        self.completed.push(Buffer::from(&in_progress[..]));
+    }
+}
+
+impl ArrayBuilder for StringViewBuilder {
+    /// Returns the builder as a non-mutable `Any` reference.
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    /// Appends data from other arrays into the builder
+    ///
+    /// This is most useful when concatenating arrays of the same type into a builder.
+    fn append_data(&mut self, data: &[ArrayDataRef]) -> Result<()> {
+        if data.iter().any(|d| d.data_type() != &DataType::Utf8View) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Data types of the arrays to append must be Utf8View".to_string(),
+            ));
+        }
+        for d in data {
+            let array = StringViewArray::from(d.clone());
+            for i in 0..array.len() {
+                if array.is_null(i) {
+                    self.append_null()?;
+                } else {
+                    self.append_value(array.value(i))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the data type of the builder
+    ///
+    /// This is used for validating array data types in `append_data`
+    fn data_type(&self) -> DataType {
+        DataType::Utf8View
+    }
+
+    /// Returns the builder as a mutable `Any` reference.
+    fn as_any_mut(&mut self) -> &mut Any {
+        self
+    }
+
+    /// Returns the boxed builder as a box of `Any`.
+    fn into_box_any(self: Box<Self>) -> Box<Any> {
+        self
+    }
+
+    /// Returns the number of array slots in the builder
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the number of array slots is zero
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Builds the array and reset this builder.
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_view_array() {
+        let long = "a-very-long-string-over-twelve-bytes";
+        let mut builder = StringViewBuilder::new(3);
+        builder.append_value("hi").unwrap();
+        builder.append_value(long).unwrap();
+        builder.append_null().unwrap();
+        let array = builder.finish();
+
+        assert_eq!(3, array.len());
+        assert_eq!(1, array.null_count());
+        assert_eq!("hi", array.value(0));
+        assert_eq!(2, array.value_length(0));
+        assert_eq!(long, array.value(1));
+        assert_eq!(long.len(), array.value_length(1));
+        assert!(array.is_null(2));
+        assert_eq!(1, array.data_buffers().len());
+        assert_eq!(long.as_bytes(), array.data_buffers()[0].data());
+        assert_eq!(&DataType::Utf8View, array.data_type());
+    }
+
+    #[test]
+    fn test_string_view_inline_boundary() {
+        let array = StringViewArray::from(vec![
+            Some("twelve bytes"),
+            Some("thirteen byte"),
+            Some(""),
+        ]);
+        assert_eq!("twelve bytes", array.value(0));
+        assert_eq!("thirteen byte", array.value(1));
+        assert_eq!("", array.value(2));
+        // only the string longer than 12 bytes is stored in a data buffer
+        assert_eq!(13, array.data_buffers()[0].len());
+    }
+
+    #[test]
+    fn test_string_view_spills_into_new_blocks() {
+        let values = vec!["first string of the batch", "second string in a block"];
+        let mut builder = StringViewBuilder::with_block_size(2, 30);
+        for value in &values {
+            builder.append_value(value).unwrap();
+        }
+        builder.append_value("short").unwrap();
+        let array = builder.finish();
+
+        assert_eq!(2, array.data_buffers().len());
+        assert_eq!(values[0], array.value(0));
+        assert_eq!(values[1], array.value(1));
+        assert_eq!("short", array.value(2));
+
+        // slices keep pointing into the same views and data buffers
+        let sliced = array.slice(1, 2);
+        let sliced = sliced.as_any().downcast_ref::<StringViewArray>().unwrap();
+        assert_eq!(values[1], sliced.value(0));
+        assert_eq!("short", sliced.value(1));
+
+        let expected = StringViewArray::from(vec![Some(values[1]), Some("short")]);
+        assert_eq!(&expected, sliced);
+        assert_ne!(expected, array);
+    }
+}
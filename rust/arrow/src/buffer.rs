@@ -42,13 +42,16 @@ use std::borrow::BorrowMut;
 
 /// Buffer is a contiguous memory region of fixed size and is aligned at a 64-byte
 /// boundary. Buffer is immutable.
-#[derive(PartialEq, Debug)]
+#[derive(Debug)]
 pub struct Buffer {
     /// Reference-counted pointer to the internal byte buffer.
     data: Arc<BufferData>,
 
     /// The offset into the buffer.
     offset: usize,
+
+    /// The length of the buffer in bytes, starting at `offset`.
+    length: usize,
 }
 
 struct BufferData {
@@ -68,12 +71,10 @@ struct BufferData {
     capacity: usize,
 }
 
-impl PartialEq for BufferData {
-    fn eq(&self, other: &BufferData) -> bool {
-        if self.capacity != other.capacity {
-            return false;
-        }
-
+/// Buffers are equal if their logical bytes, i.e. the `len()` bytes starting at their
+/// offsets, are equal, regardless of the memory they are allocated in.
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Buffer) -> bool {
         self.data() == other.data()
     }
 }
@@ -194,12 +195,13 @@ impl Buffer {
         Buffer {
             data: Arc::new(buf_data),
             offset: 0,
+            length: len,
         }
     }
 
     /// Returns the number of bytes in the buffer
     pub fn len(&self) -> usize {
-        self.data.len - self.offset
+        self.length
     }
 
     /// Returns the capacity of this buffer
@@ -209,12 +211,13 @@ impl Buffer {
 
     /// Returns whether the buffer is empty.
     pub fn is_empty(&self) -> bool {
-        self.data.len - self.offset == 0
+        self.length == 0
     }
 
-    /// Returns the byte slice stored in this buffer
+    /// Returns the byte slice stored in this buffer, i.e. the `len()` bytes starting
+    /// at its offset
     pub fn data(&self) -> &[u8] {
-        &self.data.data()[self.offset..]
+        &self.data.data()[self.offset..self.offset + self.length]
     }
 
     /// Returns a slice of this buffer, starting from `offset`.
@@ -226,6 +229,20 @@ impl Buffer {
         Self {
             data: self.data.clone(),
             offset: self.offset + offset,
+            length: self.length - offset,
+        }
+    }
+
+    /// Returns a slice of this buffer of `length` bytes, starting from `offset`.
+    pub fn slice_with_length(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset and length of the new Buffer cannot exceed the existing length"
+        );
+        Self {
+            data: self.data.clone(),
+            offset: self.offset + offset,
+            length,
         }
     }
 
@@ -271,9 +288,41 @@ impl Buffer {
         BitChunks::new(&self, offset, len)
     }
 
+    /// Converts this buffer back into a `MutableBuffer` without copying, returning the
+    /// buffer unchanged if that's not possible.
+    ///
+    /// This only succeeds if the buffer is the sole owner of its memory and starts at
+    /// the beginning of it, i.e. it is not a slice of or shared with another buffer.
+    pub fn into_mutable(self) -> std::result::Result<MutableBuffer, Buffer> {
+        if self.offset != 0 || !self.data.owned {
+            return Err(self);
+        }
+        let length = self.length;
+        match Arc::try_unwrap(self.data) {
+            Ok(data) => {
+                let buffer = MutableBuffer {
+                    data: data.ptr as *mut u8,
+                    len: length,
+                    capacity: data.capacity,
+                };
+                // the memory is now owned by the mutable buffer
+                mem::forget(data);
+                Ok(buffer)
+            }
+            Err(data) => Err(Buffer {
+                data,
+                offset: 0,
+                length,
+            }),
+        }
+    }
+
     /// Returns an empty buffer.
+    ///
+    /// The buffer points to the aligned sentinel used for zero-sized allocations, so
+    /// it does not allocate.
     pub fn empty() -> Self {
-        unsafe { Self::from_raw_parts(BUFFER_INIT.as_ptr() as _, 0, 0) }
+        unsafe { Self::from_raw_parts(memory::allocate_aligned(0), 0, 0) }
     }
 }
 
@@ -282,6 +331,7 @@ impl Clone for Buffer {
         Buffer {
             data: self.data.clone(),
             offset: self.offset,
+            length: self.length,
         }
     }
 }
@@ -616,6 +666,9 @@ pub struct MutableBuffer {
 
 impl MutableBuffer {
     /// Allocate a new mutable buffer with initial capacity to be `capacity`.
+    ///
+    /// A `capacity` of 0 does not call the allocator: the buffer holds an aligned
+    /// dangling pointer until it first grows, and freezes into an empty `Buffer`.
     pub fn new(capacity: usize) -> Self {
         let new_capacity = bit_util::round_upto_multiple_of_64(capacity);
         let ptr = memory::allocate_aligned(new_capacity);
@@ -657,13 +710,33 @@ impl MutableBuffer {
     /// Ensures that this buffer has at least `capacity` slots in this buffer. This will
     /// also ensure the new capacity will be a multiple of 64 bytes.
     ///
-    /// Returns the new capacity for this buffer.
+    /// Returns the new capacity for this buffer, or an error if the new capacity
+    /// exceeds `isize::MAX`, the largest size that can be allocated, or if the
+    /// allocation fails.
     pub fn reserve(&mut self, capacity: usize) -> Result<usize> {
         if capacity > self.capacity {
-            let new_capacity = bit_util::round_upto_multiple_of_64(capacity);
-            let new_capacity = cmp::max(new_capacity, self.capacity * 2);
+            let overflow = || {
+                ArrowError::MemoryError(format!(
+                    "Cannot reserve {} bytes in a buffer of capacity {}",
+                    capacity, self.capacity
+                ))
+            };
+            let new_capacity = capacity
+                .checked_add(63)
+                .map(|c| c & !63)
+                .ok_or_else(overflow)?;
+            let new_capacity = cmp::max(
+                new_capacity,
+                self.capacity.checked_mul(2).ok_or_else(overflow)?,
+            );
+            if new_capacity > isize::MAX as usize {
+                return Err(overflow());
+            }
             let new_data =
                 unsafe { memory::reallocate(self.data, self.capacity, new_capacity) };
+            if new_data.is_null() {
+                return Err(overflow());
+            }
             self.data = new_data as *mut u8;
             self.capacity = new_capacity;
         }
@@ -747,6 +820,7 @@ impl MutableBuffer {
 
     /// Freezes this buffer and return an immutable version of it.
     pub fn freeze(self) -> Buffer {
+        let length = self.len;
         let buffer_data = BufferData {
             ptr: self.data,
             len: self.len,
@@ -757,6 +831,7 @@ impl MutableBuffer {
         Buffer {
             data: Arc::new(buffer_data),
             offset: 0,
+            length,
         }
     }
 
@@ -812,7 +887,13 @@ impl PartialEq for MutableBuffer {
 
 impl Write for MutableBuffer {
     fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
-        let remaining_capacity = self.capacity - self.len;
+        debug_assert!(
+            self.len <= self.capacity,
+            "MutableBuffer length {} exceeds its capacity {}",
+            self.len,
+            self.capacity
+        );
+        let remaining_capacity = self.capacity.saturating_sub(self.len);
         if buf.len() > remaining_capacity {
             return Err(IoError::new(ErrorKind::Other, "Buffer not big enough"));
         }
@@ -838,6 +919,7 @@ mod tests {
     use std::thread;
 
     use super::*;
+    use crate::array::Array;
     use crate::datatypes::ToByteSlice;
 
     #[test]
@@ -861,6 +943,19 @@ mod tests {
         assert_ne!(buf1, buf2);
     }
 
+    #[test]
+    fn test_buffer_equality_of_slices_with_different_parents() {
+        let parent1 = Buffer::from(&[9, 9, 1, 2, 3]);
+        let parent2 = Buffer::from(&[1, 2, 3, 7, 7, 7, 7, 7]);
+        assert_eq!(parent1.slice(2), parent2.slice_with_length(0, 3));
+        assert_eq!(parent2.slice_with_length(0, 3), parent1.slice(2));
+
+        // same length, different content
+        assert_ne!(parent1.slice(2), parent2.slice_with_length(1, 3));
+        // same content, different length
+        assert_ne!(parent1.slice(2), parent2.slice_with_length(0, 2));
+    }
+
     #[test]
     fn test_from_raw_parts() {
         let buf = unsafe { Buffer::from_raw_parts(null_mut(), 0, 0) };
@@ -915,6 +1010,63 @@ mod tests {
         assert_eq!(buf2.slice(2).data(), &[10]);
     }
 
+    #[test]
+    fn test_bit_slice() {
+        let buf = Buffer::from([0b10110100u8]);
+        let sliced = buf.bit_slice(2, 4);
+        assert_eq!(0b00001101, sliced.data()[0]);
+
+        // bits spanning a byte boundary are realigned to bit 0
+        let buf = Buffer::from([0b11000000u8, 0b00000001]);
+        let sliced = buf.bit_slice(6, 3);
+        assert_eq!(0b00000111, sliced.data()[0]);
+    }
+
+    #[test]
+    fn test_slice_with_length() {
+        let buf = Buffer::from(&[2, 4, 6, 8, 10, 12]);
+        let buf2 = buf.slice_with_length(1, 4);
+        assert_eq!([4, 6, 8, 10], buf2.data());
+        assert_eq!(4, buf2.len());
+        assert_eq!(unsafe { buf.raw_data().offset(1) }, buf2.raw_data());
+
+        // the window excludes both the leading and the trailing bytes
+        let buf3 = buf2.slice_with_length(1, 2);
+        assert_eq!([6, 8], buf3.data());
+        assert_eq!(2, buf3.len());
+        assert_eq!([8], buf3.slice(1).data());
+        assert!(buf3.slice(2).is_empty());
+
+        // buffers over the same window are equal
+        assert_eq!(buf3, buf.slice_with_length(2, 2));
+        assert_ne!(buf3, buf.slice(2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the offset and length of the new Buffer cannot exceed the existing length"
+    )]
+    fn test_slice_with_length_out_of_bound() {
+        let buf = Buffer::from(&[2, 4, 6, 8, 10]);
+        buf.slice(1).slice_with_length(2, 3);
+    }
+
+    #[test]
+    fn test_into_mutable() {
+        let buf = Buffer::from(&[2, 4, 6, 8, 10]);
+        let mut mutable = buf.into_mutable().unwrap();
+        assert_eq!(5, mutable.len());
+        mutable.write_all(&[12]).unwrap();
+        assert_eq!(&[2, 4, 6, 8, 10, 12], mutable.freeze().data());
+
+        // shared and sliced buffers can't be mutated
+        let buf = Buffer::from(&[2, 4, 6]);
+        let shared = buf.clone();
+        let buf = buf.into_mutable().unwrap_err();
+        assert_eq!(shared, buf);
+        assert!(buf.slice(1).into_mutable().is_err());
+    }
+
     #[test]
     #[should_panic(
         expected = "the offset of the new Buffer cannot exceed the existing length"
@@ -984,6 +1136,35 @@ mod tests {
         assert!(buf.is_empty());
     }
 
+    #[test]
+    fn test_mutable_new_empty() {
+        let buf = MutableBuffer::new(0);
+        assert_eq!(0, buf.capacity());
+        assert!(buf.is_empty());
+        assert!(buf.data().is_empty());
+        // zero-sized requests are served with a sentinel pointer, not the allocator
+        assert_eq!(memory::allocate_aligned(0) as *const u8, buf.raw_data());
+        assert!(memory::is_aligned(buf.raw_data(), memory::ALIGNMENT));
+
+        let buffer = buf.freeze();
+        assert_eq!(0, buffer.len());
+        assert_eq!(0, buffer.capacity());
+        assert_eq!(Buffer::empty(), buffer);
+
+        // an empty builder finishes without allocating its values buffer
+        let array = crate::array::Int32Builder::new(0).finish();
+        assert_eq!(0, array.data().buffers()[0].capacity());
+        assert_eq!(Buffer::empty(), array.data().buffers()[0]);
+
+        // the buffer allocates once it grows
+        let mut buf = MutableBuffer::new(0);
+        buf.write_all(&[1]).unwrap_err();
+        buf.reserve(1).unwrap();
+        assert_eq!(64, buf.capacity());
+        buf.write_all(&[1]).unwrap();
+        assert_eq!(&[1], buf.data());
+    }
+
     #[test]
     fn test_mutable_write() {
         let mut buf = MutableBuffer::new(100);
@@ -1012,6 +1193,24 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "exceeds its capacity")]
+    fn test_mutable_write_len_exceeds_capacity() {
+        let mut buf = MutableBuffer::new(1);
+        buf.len = buf.capacity + 1;
+        let _ = buf.write(&[0]);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn test_mutable_write_len_exceeds_capacity() {
+        let mut buf = MutableBuffer::new(1);
+        buf.len = buf.capacity + 1;
+        assert!(buf.write(&[0]).is_err());
+        buf.len = 0;
+    }
+
     #[test]
     fn test_mutable_reserve() {
         let mut buf = MutableBuffer::new(1);
@@ -1027,6 +1226,31 @@ mod tests {
         assert_eq!(128, buf.capacity());
     }
 
+    #[test]
+    fn test_mutable_reserve_overflow() {
+        let mut buf = MutableBuffer::new(1);
+        match buf.reserve(usize::MAX) {
+            Err(ArrowError::MemoryError(_)) => {}
+            other => panic!("expected a memory error, got {:?}", other),
+        }
+        match buf.reserve(usize::MAX - 10) {
+            Err(ArrowError::MemoryError(_)) => {}
+            other => panic!("expected a memory error, got {:?}", other),
+        }
+        // rounds up without overflowing, but is too large to allocate
+        match buf.reserve(isize::MAX as usize + 1) {
+            Err(ArrowError::MemoryError(_)) => {}
+            other => panic!("expected a memory error, got {:?}", other),
+        }
+        match buf.reserve(usize::MAX - 63) {
+            Err(ArrowError::MemoryError(_)) => {}
+            other => panic!("expected a memory error, got {:?}", other),
+        }
+        // the buffer is still usable after a failed reservation
+        assert_eq!(64, buf.capacity());
+        assert_eq!(128, buf.reserve(100).unwrap());
+    }
+
     #[test]
     fn test_mutable_resize() {
         let mut buf = MutableBuffer::new(1);
@@ -17,10 +17,16 @@
 
 //! Defines aggregations over Arrow arrays.
 
+use std::collections::HashSet;
 use std::ops::Add;
 
-use crate::array::{Array, GenericStringArray, PrimitiveArray, StringOffsetSizeTrait};
-use crate::datatypes::ArrowNumericType;
+use crate::array::{
+    Array, ArrayRef, GenericStringArray, PrimitiveArray, PrimitiveArrayOps,
+    StringOffsetSizeTrait,
+};
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::scalar::ScalarValue;
 
 /// Helper macro to perform min/max of strings
 fn min_max_string<T: StringOffsetSizeTrait, F: Fn(&str, &str) -> bool>(
@@ -72,6 +78,69 @@ where
     min_max_helper(array, |a, b| a < b)
 }
 
+/// Returns both the minimum and the maximum value in the array, computed in a single
+/// pass over the valid slots.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn min_max<T>(array: &PrimitiveArray<T>) -> Option<(T::Native, T::Native)>
+where
+    T: ArrowNumericType,
+{
+    let null_count = array.null_count();
+
+    if null_count == array.len() {
+        return None;
+    }
+
+    let m = array.value_slice(0, array.len());
+    let mut bounds: Option<(T::Native, T::Native)> = None;
+    let mut update = |item: T::Native| {
+        bounds = match bounds {
+            None => Some((item, item)),
+            Some((min, max)) => Some((
+                if min > item { item } else { min },
+                if max < item { item } else { max },
+            )),
+        }
+    };
+
+    if null_count == 0 {
+        // optimized path for arrays without null values
+        m.iter().for_each(|item| update(*item));
+    } else {
+        m.iter()
+            .enumerate()
+            .filter(|(i, _)| array.is_valid(*i))
+            .for_each(|(_, item)| update(*item));
+    }
+    bounds
+}
+
+/// Returns the number of non-null values in the array.
+///
+/// The nulls are counted from the validity bitmap over the range of the array, so
+/// this is correct for slices even if the stored null count is not.
+pub fn count(array: &Array) -> i64 {
+    (array.len() - array.logical_null_count()) as i64
+}
+
+/// Returns the number of distinct non-null values in the array.
+///
+/// Values are compared by their binary representation, so for floating point arrays
+/// `0.0` and `-0.0` are counted as different values while identical `NaN`s are not.
+pub fn count_distinct<T>(array: &PrimitiveArray<T>) -> i64
+where
+    T: ArrowPrimitiveType,
+{
+    let mut seen = HashSet::new();
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            seen.insert(array.value(i).to_byte_slice().to_vec());
+        }
+    }
+    seen.len() as i64
+}
+
 /// Returns the maximum value in the string array, according to the natural order.
 pub fn max_string<T: StringOffsetSizeTrait>(
     array: &GenericStringArray<T>,
@@ -259,10 +328,138 @@ where
     Some(total_sum)
 }
 
+/// Helper macro to sum an `ArrayRef` of a given primitive type into a `ScalarValue`
+macro_rules! sum_dyn_helper {
+    ($ARRAY:expr, $ARROW_TYPE:ident, $SCALAR:ident) => {{
+        let array = $ARRAY
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ARROW_TYPE>>()
+            .unwrap();
+        Ok(sum(array).map_or(ScalarValue::Null, ScalarValue::$SCALAR))
+    }};
+}
+
+/// Returns the sum of values in an array of any numeric type as a `ScalarValue`.
+///
+/// Returns `ScalarValue::Null` if the array is empty or only contains null values,
+/// and an error if the data type of the array is not numeric.
+pub fn sum_dyn(array: &ArrayRef) -> Result<ScalarValue> {
+    match array.data_type() {
+        DataType::Int8 => sum_dyn_helper!(array, Int8Type, Int8),
+        DataType::Int16 => sum_dyn_helper!(array, Int16Type, Int16),
+        DataType::Int32 => sum_dyn_helper!(array, Int32Type, Int32),
+        DataType::Int64 => sum_dyn_helper!(array, Int64Type, Int64),
+        DataType::UInt8 => sum_dyn_helper!(array, UInt8Type, UInt8),
+        DataType::UInt16 => sum_dyn_helper!(array, UInt16Type, UInt16),
+        DataType::UInt32 => sum_dyn_helper!(array, UInt32Type, UInt32),
+        DataType::UInt64 => sum_dyn_helper!(array, UInt64Type, UInt64),
+        DataType::Float32 => sum_dyn_helper!(array, Float32Type, Float32),
+        DataType::Float64 => sum_dyn_helper!(array, Float64Type, Float64),
+        t => Err(ArrowError::ComputeError(format!(
+            "sum_dyn not supported for {:?}",
+            t
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::array::*;
+    use crate::buffer::Buffer;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_sum_dyn() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        match sum_dyn(&a).unwrap() {
+            ScalarValue::Int32(v) => assert_eq!(4, v),
+            v => panic!("unexpected scalar {:?}", v),
+        }
+
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![1.5, 2.25, -0.5]));
+        match sum_dyn(&a).unwrap() {
+            ScalarValue::Float64(v) => assert_eq!(3.25, v),
+            v => panic!("unexpected scalar {:?}", v),
+        }
+
+        let a: ArrayRef = Arc::new(Int64Array::from(vec![None, None]));
+        assert_eq!(ScalarValue::Null, sum_dyn(&a).unwrap());
+
+        let a: ArrayRef = Arc::new(StringArray::from(vec!["a"]));
+        assert!(sum_dyn(&a).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_min_max_one_pass() {
+        let a = Int32Array::from(vec![3, 1, 4, 1, 5]);
+        assert_eq!(Some((1, 5)), min_max(&a));
+    }
+
+    #[test]
+    fn test_primitive_array_min_max_with_nulls() {
+        let a = Int32Array::from(vec![None, Some(7), None, Some(-2), Some(4)]);
+        assert_eq!(Some((-2, 7)), min_max(&a));
+
+        let a = Float64Array::from(vec![Some(2.5), None, Some(-1.0)]);
+        assert_eq!(Some((-1.0, 2.5)), min_max(&a));
+    }
+
+    #[test]
+    fn test_primitive_array_min_max_sliced_with_nulls() {
+        let a =
+            Int32Array::from(vec![Some(100), None, Some(7), None, Some(-2), Some(50)]);
+        let a = a.slice(1, 4);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(Some((-2, 7)), min_max(a));
+    }
+
+    #[test]
+    fn test_primitive_array_min_max_empty_or_all_null() {
+        let a = Int32Array::from(Vec::<i32>::new());
+        assert_eq!(None, min_max(&a));
+
+        let a = Int32Array::from(vec![None, None]);
+        assert_eq!(None, min_max(&a));
+    }
+
+    #[test]
+    fn test_count() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(2, count(&a));
+
+        let a = a.slice(1, 2);
+        assert_eq!(1, count(a.as_ref()));
+
+        // [1, null, 3, null] viewed from offset 1, with a stale null count of 0
+        let data = ArrayData::new(
+            DataType::Int32,
+            3,
+            Some(0),
+            Some(Buffer::from([0b00000101])),
+            1,
+            vec![Buffer::from(&[1, 0, 3, 0].to_byte_slice())],
+            vec![],
+        );
+        let a = Int32Array::from(Arc::new(data));
+        assert_eq!(0, a.null_count());
+        assert_eq!(1, count(&a));
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let a = Int32Array::from(vec![Some(1), Some(1), Some(2), None]);
+        assert_eq!(2, count_distinct(&a));
+
+        let a = Float64Array::from(vec![Some(1.5), None, Some(1.5), Some(-3.0)]);
+        assert_eq!(2, count_distinct(&a));
+
+        let a = BooleanArray::from(vec![Some(true), Some(true), None]);
+        assert_eq!(1, count_distinct(&a));
+
+        let a = Int32Array::from(vec![None, None]);
+        assert_eq!(0, count_distinct(&a));
+    }
 
     #[test]
     fn test_primitive_array_sum() {
@@ -29,7 +29,10 @@ use std::ops::{Add, Div, Mul, Sub};
 use std::slice::from_raw_parts_mut;
 use std::sync::Arc;
 
-use num::{One, Zero};
+use num::traits::{
+    CheckedAdd, CheckedRem, CheckedSub, Saturating, WrappingAdd, WrappingSub,
+};
+use num::{Float, One, Zero};
 
 #[cfg(feature = "simd")]
 use crate::bitmap::Bitmap;
@@ -85,6 +88,65 @@ where
     Ok(PrimitiveArray::<T>::from(Arc::new(data)))
 }
 
+/// Helper function to perform a fallible math lambda function on values from two arrays,
+/// skipping slots where either value is null.
+///
+/// # Errors
+///
+/// This function errors if the arrays have different lengths or if `op` returns `None`
+/// for a non-null slot.
+fn math_checked_op<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> Option<T::Native>,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let null_bit_buffer =
+        combine_option_bitmap(left.data_ref(), right.data_ref(), left.len())?;
+
+    let mut values = Vec::with_capacity(left.len());
+    for i in 0..left.len() {
+        let is_valid = match &null_bit_buffer {
+            Some(b) => unsafe { bit_util::get_bit_raw(b.raw_data(), i) },
+            None => true,
+        };
+        if is_valid {
+            match op(left.value(i), right.value(i)) {
+                Some(value) => values.push(value),
+                None => {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Overflow happened on: {:?}, {:?}",
+                        left.value(i),
+                        right.value(i)
+                    )))
+                }
+            }
+        } else {
+            values.push(T::default_value());
+        }
+    }
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        left.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(values.to_byte_slice())],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
 /// Helper function to divide two arrays.
 ///
 /// # Errors
@@ -283,6 +345,54 @@ where
     math_op(left, right, |a, b| a + b)
 }
 
+/// Behaviour of integer arithmetic kernels such as [`add_with`] when a result does not
+/// fit into the native type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// Wrap around at the boundary of the type, e.g. `i8::MAX + 1 == i8::MIN`
+    Wrapping,
+    /// Return an `ArrowError::ComputeError` on overflow
+    Checked,
+    /// Clamp the result to the minimum or maximum value of the type
+    Saturating,
+}
+
+/// Perform `left + right` operation on two integer arrays, handling overflow according
+/// to `mode`. If either left or right value is null then the result is also null.
+pub fn add_with<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    mode: OverflowMode,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: WrappingAdd + CheckedAdd + Saturating,
+{
+    match mode {
+        OverflowMode::Wrapping => math_op(left, right, |a, b| a.wrapping_add(&b)),
+        OverflowMode::Checked => math_checked_op(left, right, |a, b| a.checked_add(&b)),
+        OverflowMode::Saturating => math_op(left, right, |a, b| a.saturating_add(b)),
+    }
+}
+
+/// Perform `left - right` operation on two integer arrays, handling overflow according
+/// to `mode`. If either left or right value is null then the result is also null.
+pub fn subtract_with<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    mode: OverflowMode,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: WrappingSub + CheckedSub + Saturating,
+{
+    match mode {
+        OverflowMode::Wrapping => math_op(left, right, |a, b| a.wrapping_sub(&b)),
+        OverflowMode::Checked => math_checked_op(left, right, |a, b| a.checked_sub(&b)),
+        OverflowMode::Saturating => math_op(left, right, |a, b| a.saturating_sub(b)),
+    }
+}
+
 /// Perform `left - right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 pub fn subtract<T>(
@@ -348,6 +458,189 @@ where
     math_divide(&left, &right)
 }
 
+/// Behaviour of [`divide_with`] when a right hand value is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivideByZeroMode {
+    /// Return `Err(ArrowError::DivideByZero)`, as [`divide`] does
+    Error,
+    /// Set the result of integer divisions by zero to null. Floating point divisions
+    /// follow IEEE 754 and produce infinity or `NaN`.
+    Null,
+}
+
+/// Perform `left / right` operation on two arrays, handling zero right hand values
+/// according to `mode`. If either left or right value is null then the result is also
+/// null.
+pub fn divide_with<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    mode: DivideByZeroMode,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Add<Output = T::Native>
+        + Sub<Output = T::Native>
+        + Mul<Output = T::Native>
+        + Div<Output = T::Native>
+        + Zero
+        + One,
+{
+    match mode {
+        DivideByZeroMode::Error => divide(left, right),
+        DivideByZeroMode::Null => math_divide_null_on_zero(left, right),
+    }
+}
+
+/// Helper function to divide two arrays, setting integer divisions by zero to null.
+fn math_divide_null_on_zero<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Div<Output = T::Native> + Zero,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let is_float = match T::DATA_TYPE {
+        datatypes::DataType::Float16
+        | datatypes::DataType::Float32
+        | datatypes::DataType::Float64 => true,
+        _ => false,
+    };
+
+    Ok((0..left.len())
+        .map(|i| {
+            if left.is_null(i) || right.is_null(i) {
+                return None;
+            }
+            let right_value = right.value(i);
+            if right_value.is_zero() && !is_float {
+                None
+            } else {
+                Some(left.value(i) / right_value)
+            }
+        })
+        .collect())
+}
+
+/// Helper function to apply `op` to each value of a floating point array, keeping the
+/// null bitmap of `array`.
+fn float_unary_op<T, F>(array: &PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+    F: Fn(T::Native) -> T::Native,
+{
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let values = (0..array.len())
+        .map(|i| op(array.value(i)))
+        .collect::<Vec<T::Native>>();
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        array.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(values.to_byte_slice())],
+        vec![],
+    );
+    PrimitiveArray::<T>::from(Arc::new(data))
+}
+
+/// Raise each value of a floating point array to the power `exp`. Null values stay null.
+pub fn powf<T>(base: &PrimitiveArray<T>, exp: T::Native) -> PrimitiveArray<T>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    float_unary_op(base, |v| v.powf(exp))
+}
+
+/// Round each value of a floating point array to `decimals` decimal places, rounding
+/// half-way cases away from zero. A negative `decimals` rounds to the left of the
+/// decimal point. Null values stay null.
+pub fn round<T>(array: &PrimitiveArray<T>, decimals: i32) -> PrimitiveArray<T>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    let factor = <T::Native as num::NumCast>::from(10)
+        .unwrap()
+        .powi(decimals);
+    float_unary_op(array, |v| (v * factor).round() / factor)
+}
+
+/// Round each value of a floating point array down to the nearest integer. Null values
+/// stay null.
+pub fn floor<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    float_unary_op(array, |v| v.floor())
+}
+
+/// Round each value of a floating point array up to the nearest integer. Null values
+/// stay null.
+pub fn ceil<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    float_unary_op(array, |v| v.ceil())
+}
+
+/// Raise each value of `base` to the power of the value of `exp` at the same position.
+/// If either value is null then the result is also null.
+pub fn pow<T>(
+    base: &PrimitiveArray<T>,
+    exp: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    math_op(base, exp, |a, b| a.powf(b))
+}
+
+/// Perform `left % right` operation on two arrays of integers. If either left or right
+/// value is null then the result is also null. If a right hand value is zero, or the
+/// remainder overflows (e.g. `i32::MIN % -1`), the result is also null.
+pub fn modulus<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: CheckedRem,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    Ok((0..left.len())
+        .map(|i| {
+            if left.is_null(i) || right.is_null(i) {
+                None
+            } else {
+                left.value(i).checked_rem(&right.value(i))
+            }
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -365,6 +658,76 @@ mod tests {
         assert_eq!(17, c.value(4));
     }
 
+    #[test]
+    fn test_primitive_array_powf() {
+        let a = Float64Array::from(vec![Some(2.0), Some(3.0), None]);
+        let b = powf(&a, 2.0);
+        assert_eq!(Float64Array::from(vec![Some(4.0), Some(9.0), None]), b);
+
+        let a = a.slice(1, 2);
+        let a = a.as_any().downcast_ref::<Float64Array>().unwrap();
+        let b = powf(a, 0.5);
+        assert_eq!(Float64Array::from(vec![Some(3.0f64.sqrt()), None]), b);
+    }
+
+    #[test]
+    fn test_primitive_array_round() {
+        let a = Float64Array::from(vec![Some(1.234), Some(5.678), None]);
+        let b = round(&a, 1);
+        assert_eq!(Float64Array::from(vec![Some(1.2), Some(5.7), None]), b);
+
+        let b = round(&a, 0);
+        assert_eq!(Float64Array::from(vec![Some(1.0), Some(6.0), None]), b);
+
+        let a = Float64Array::from(vec![Some(-2.5), Some(1234.5)]);
+        let b = round(&a, -2);
+        assert_eq!(Float64Array::from(vec![Some(-0.0), Some(1200.0)]), b);
+        let b = round(&a, 0);
+        assert_eq!(Float64Array::from(vec![Some(-3.0), Some(1235.0)]), b);
+    }
+
+    #[test]
+    fn test_primitive_array_floor_ceil() {
+        let a = Float32Array::from(vec![Some(1.5), None, Some(-1.5), Some(2.0)]);
+        let b = floor(&a);
+        assert_eq!(
+            Float32Array::from(vec![Some(1.0), None, Some(-2.0), Some(2.0)]),
+            b
+        );
+        let b = ceil(&a);
+        assert_eq!(
+            Float32Array::from(vec![Some(2.0), None, Some(-1.0), Some(2.0)]),
+            b
+        );
+    }
+
+    #[test]
+    fn test_primitive_array_pow() {
+        let a = Float32Array::from(vec![Some(2.0), Some(4.0), None, Some(9.0)]);
+        let b = Float32Array::from(vec![Some(3.0), Some(-1.0), Some(2.0), None]);
+        let c = pow(&a, &b).unwrap();
+        assert_eq!(
+            Float32Array::from(vec![Some(8.0), Some(0.25), None, None]),
+            c
+        );
+
+        let b = Float32Array::from(vec![1.0]);
+        assert!(pow(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_modulus() {
+        let a = Int32Array::from(vec![10, 20, 30]);
+        let b = Int32Array::from(vec![3, 0, 7]);
+        let c = modulus(&a, &b).unwrap();
+        assert_eq!(Int32Array::from(vec![Some(1), None, Some(2)]), c);
+
+        let a = Int32Array::from(vec![Some(-7), None, Some(i32::MIN), Some(5)]);
+        let b = Int32Array::from(vec![Some(2), Some(1), Some(-1), None]);
+        let c = modulus(&a, &b).unwrap();
+        assert_eq!(Int32Array::from(vec![Some(-1), None, None, None]), c);
+    }
+
     #[test]
     fn test_primitive_array_add_sliced() {
         let a = Int32Array::from(vec![0, 0, 0, 5, 6, 7, 8, 9, 0]);
@@ -399,6 +762,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_primitive_array_mismatched_length_is_compute_error() {
+        let a = Int32Array::from(vec![5, 6, 7]);
+        let b = Int32Array::from(vec![6, 7]);
+        assert!(matches!(add(&a, &b), Err(ArrowError::ComputeError(_))));
+        assert!(matches!(subtract(&a, &b), Err(ArrowError::ComputeError(_))));
+        assert!(matches!(multiply(&a, &b), Err(ArrowError::ComputeError(_))));
+        assert!(matches!(divide(&a, &b), Err(ArrowError::ComputeError(_))));
+    }
+
     #[test]
     fn test_primitive_array_subtract() {
         let a = Int32Array::from(vec![1, 2, 3, 4, 5]);
@@ -527,6 +900,30 @@ mod tests {
         divide(&a, &b).unwrap();
     }
 
+    #[test]
+    fn test_primitive_array_divide_with_null_on_zero() {
+        let a = Int32Array::from(vec![Some(10), Some(20), None]);
+        let b = Int32Array::from(vec![Some(2), Some(0), Some(0)]);
+        let c = divide_with(&a, &b, DivideByZeroMode::Null).unwrap();
+        assert_eq!(3, c.len());
+        assert_eq!(5, c.value(0));
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+
+        assert!(divide_with(&a, &b, DivideByZeroMode::Error).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_divide_with_f64_by_zero() {
+        let a = Float64Array::from(vec![1.0, -1.0, 0.0]);
+        let b = Float64Array::from(vec![0.0, 0.0, 0.0]);
+        let c = divide_with(&a, &b, DivideByZeroMode::Null).unwrap();
+        assert_eq!(0, c.null_count());
+        assert_eq!(f64::INFINITY, c.value(0));
+        assert_eq!(f64::NEG_INFINITY, c.value(1));
+        assert!(c.value(2).is_nan());
+    }
+
     #[test]
     fn test_primitive_array_divide_f64() {
         let a = Float64Array::from(vec![15.0, 15.0, 8.0]);
@@ -548,4 +945,50 @@ mod tests {
         assert_eq!(true, c.is_null(3));
         assert_eq!(13, c.value(2));
     }
+
+    #[test]
+    fn test_primitive_array_add_with_overflow_modes() {
+        let a = Int8Array::from(vec![Some(127), Some(-128), None, Some(1)]);
+        let b = Int8Array::from(vec![Some(1), Some(-1), Some(1), Some(2)]);
+
+        let c = add_with(&a, &b, OverflowMode::Wrapping).unwrap();
+        assert_eq!(-128, c.value(0));
+        assert_eq!(127, c.value(1));
+        assert!(c.is_null(2));
+        assert_eq!(3, c.value(3));
+
+        let c = add_with(&a, &b, OverflowMode::Saturating).unwrap();
+        assert_eq!(127, c.value(0));
+        assert_eq!(-128, c.value(1));
+        assert!(c.is_null(2));
+        assert_eq!(3, c.value(3));
+
+        let e = add_with(&a, &b, OverflowMode::Checked).unwrap_err();
+        assert_eq!(
+            "Compute error: Overflow happened on: 127, 1",
+            format!("{}", e)
+        );
+
+        let a = Int8Array::from(vec![Some(126), None]);
+        let b = Int8Array::from(vec![Some(1), Some(127)]);
+        let c = add_with(&a, &b, OverflowMode::Checked).unwrap();
+        assert_eq!(127, c.value(0));
+        assert!(c.is_null(1));
+    }
+
+    #[test]
+    fn test_primitive_array_subtract_with_overflow_modes() {
+        let a = Int8Array::from(vec![-128, 127]);
+        let b = Int8Array::from(vec![1, -1]);
+
+        let c = subtract_with(&a, &b, OverflowMode::Wrapping).unwrap();
+        assert_eq!(127, c.value(0));
+        assert_eq!(-128, c.value(1));
+
+        let c = subtract_with(&a, &b, OverflowMode::Saturating).unwrap();
+        assert_eq!(-128, c.value(0));
+        assert_eq!(127, c.value(1));
+
+        assert!(subtract_with(&a, &b, OverflowMode::Checked).is_err());
+    }
 }
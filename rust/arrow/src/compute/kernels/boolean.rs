@@ -22,9 +22,10 @@
 //! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
 //! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
 
+use std::io::Write;
 use std::sync::Arc;
 
-use crate::array::{Array, ArrayData, ArrayRef, BooleanArray};
+use crate::array::{Array, ArrayData, ArrayRef, BooleanArray, PrimitiveArrayOps};
 use crate::buffer::{
     buffer_bin_and, buffer_bin_or, buffer_unary_not, Buffer, MutableBuffer,
 };
@@ -111,14 +112,101 @@ pub fn not(left: &BooleanArray) -> Result<BooleanArray> {
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
+/// Performs `left AND NOT right` on two arrays in a single pass over their bits, without
+/// materializing `NOT right`.
+///
+/// Nulls follow Kleene logic: the result is `false` if either the left value is `false`
+/// or the right value is `true`, even if the other value is null. Otherwise, the result
+/// is null if either value is null.
+pub fn and_not(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform bitwise operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let len = left.len();
+    let left_data = left.data_ref();
+    let right_data = right.data_ref();
+
+    let left_values = left_data.buffers()[0].bit_chunks(left.offset(), len);
+    let right_values = right_data.buffers()[0].bit_chunks(right.offset(), len);
+    let left_valid = left_data
+        .null_buffer()
+        .map(|b| b.bit_chunks(left.offset(), len));
+    let right_valid = right_data
+        .null_buffer()
+        .map(|b| b.bit_chunks(right.offset(), len));
+    let has_nulls = left_valid.is_some() || right_valid.is_some();
+
+    // returns the (values, validity) bits of 64 slots, with all slots valid if
+    // neither array has a null buffer
+    let op = |a: u64, va: u64, b: u64, vb: u64| {
+        // known to be true / false on each side
+        let a_true = a & va;
+        let a_false = !a & va;
+        let b_true = b & vb;
+        let b_false = !b & vb;
+        (a_true & b_false, (va & vb) | a_false | b_true)
+    };
+
+    let len_bytes = ceil(len, 8);
+    let mut values = MutableBuffer::new(len_bytes);
+    let mut validity = MutableBuffer::new(if has_nulls { len_bytes } else { 0 });
+
+    let mut left_valid_iter = left_valid.as_ref().map(|c| c.iter());
+    let mut right_valid_iter = right_valid.as_ref().map(|c| c.iter());
+    for (a, b) in left_values.iter().zip(right_values.iter()) {
+        let va = left_valid_iter.as_mut().map_or(!0, |i| i.next().unwrap());
+        let vb = right_valid_iter.as_mut().map_or(!0, |i| i.next().unwrap());
+        let (value, valid) = op(a, va, b, vb);
+        values.write_all(&value.to_le_bytes())?;
+        if has_nulls {
+            validity.write_all(&valid.to_le_bytes())?;
+        }
+    }
+
+    let remainder_bytes = ceil(left_values.remainder_len(), 8);
+    let (value, valid) = op(
+        left_values.remainder_bits(),
+        left_valid.as_ref().map_or(!0, |c| c.remainder_bits()),
+        right_values.remainder_bits(),
+        right_valid.as_ref().map_or(!0, |c| c.remainder_bits()),
+    );
+    values.write_all(&value.to_le_bytes()[..remainder_bytes])?;
+    if has_nulls {
+        validity.write_all(&valid.to_le_bytes()[..remainder_bytes])?;
+    }
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        None,
+        if has_nulls {
+            Some(validity.freeze())
+        } else {
+            None
+        },
+        0,
+        vec![values.freeze()],
+        vec![],
+    );
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
+/// Returns a non-null `BooleanArray` with whether each value of the array is null.
+///
+/// Works on arrays of any type through their validity bitmap.
 pub fn is_null(input: &ArrayRef) -> Result<BooleanArray> {
     let len = input.len();
 
     let output = match input.data_ref().null_buffer() {
         None => {
+            // a `NullArray` has no null buffer, but all of its slots are null
+            let all_null = input.data_type() == &DataType::Null;
             let len_bytes = ceil(len, 8);
             MutableBuffer::new(len_bytes)
-                .with_bitset(len_bytes, false)
+                .with_bitset(len_bytes, all_null)
                 .freeze()
         }
         Some(buffer) => buffer_unary_not(buffer, input.offset(), len),
@@ -130,14 +218,18 @@ pub fn is_null(input: &ArrayRef) -> Result<BooleanArray> {
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
+/// Returns a non-null `BooleanArray` with whether each value of the array is not null.
+///
+/// Works on arrays of any type through their validity bitmap.
 pub fn is_not_null(input: &ArrayRef) -> Result<BooleanArray> {
     let len = input.len();
 
     let output = match input.data_ref().null_buffer() {
         None => {
+            let all_valid = input.data_type() != &DataType::Null;
             let len_bytes = ceil(len, 8);
             MutableBuffer::new(len_bytes)
-                .with_bitset(len_bytes, true)
+                .with_bitset(len_bytes, all_valid)
                 .freeze()
         }
         Some(buffer) => buffer.bit_slice(input.offset(), len),
@@ -149,11 +241,37 @@ pub fn is_not_null(input: &ArrayRef) -> Result<BooleanArray> {
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
+/// Returns the maximal runs of equal valid values of `array` as `(start, length, value)`
+/// tuples, in order. Null values are not part of any run, so they split runs.
+///
+/// For example, `[true, true, false, null, true]` has the runs `(0, 2, true)`,
+/// `(2, 1, false)` and `(4, 1, true)`.
+pub fn boolean_runs(array: &BooleanArray) -> Vec<(usize, usize, bool)> {
+    let mut runs = Vec::new();
+    let mut current: Option<(usize, usize, bool)> = None;
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            runs.extend(current.take());
+            continue;
+        }
+        let value = array.value(i);
+        match current.as_mut() {
+            Some((_, length, run_value)) if *run_value == value => *length += 1,
+            _ => {
+                runs.extend(current.take());
+                current = Some((i, 1, value));
+            }
+        }
+    }
+    runs.extend(current);
+    runs
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::array::Int32Array;
     use crate::array::PrimitiveArrayOps;
+    use crate::array::{ArrayRef, Int32Array, NullArray, StringArray};
 
     #[test]
     fn test_bool_array_and() {
@@ -209,6 +327,65 @@ mod tests {
         assert_eq!(false, c.is_null(3));
     }
 
+    #[test]
+    fn test_bool_array_and_not() {
+        let a = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            Some(false),
+            None,
+            None,
+            None,
+            Some(true),
+            Some(false),
+        ]);
+        let b = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(false),
+            None,
+            None,
+            None,
+        ]);
+        let c = and_not(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+            None,
+            None,
+            Some(false),
+        ]);
+        assert_eq!(expected, c);
+        assert_eq!(3, c.null_count());
+    }
+
+    #[test]
+    fn test_bool_array_and_not_sliced_without_nulls() {
+        let a: Vec<bool> = (0..100).map(|i| i % 2 == 0).collect();
+        let b: Vec<bool> = (0..100).map(|i| i % 3 == 0).collect();
+        let a = BooleanArray::from(a);
+        let b = BooleanArray::from(b);
+        let a = a.slice(3, 90);
+        let b = b.slice(5, 90);
+        let a = a.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let b = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+        let c = and_not(&a, &b).unwrap();
+        assert_eq!(90, c.len());
+        assert_eq!(0, c.null_count());
+        for i in 0..90 {
+            assert_eq!(a.value(i) && !b.value(i), c.value(i), "slot {}", i);
+        }
+    }
+
     #[test]
     fn test_bool_array_and_sliced_same_offset() {
         let a = BooleanArray::from(vec![
@@ -295,6 +472,35 @@ mod tests {
         assert_eq!(true, c.value(3));
     }
 
+    #[test]
+    fn test_boolean_runs() {
+        let a = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+        ]);
+        assert_eq!(
+            vec![(0, 2, true), (2, 1, false), (4, 1, true)],
+            boolean_runs(&a)
+        );
+
+        // a null between equal values still splits the run
+        let a = BooleanArray::from(vec![Some(false), None, Some(false), Some(false)]);
+        assert_eq!(vec![(0, 1, false), (2, 2, false)], boolean_runs(&a));
+
+        let a = BooleanArray::from(vec![None, None]);
+        assert!(boolean_runs(&a).is_empty());
+        assert!(boolean_runs(&BooleanArray::from(Vec::<bool>::new())).is_empty());
+
+        // positions are relative to the start of a slice
+        let a = BooleanArray::from(vec![true, false, false, true]);
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(vec![(0, 2, false), (2, 1, true)], boolean_runs(a));
+    }
+
     #[test]
     fn test_nonnull_array_is_null() {
         let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
@@ -458,4 +664,35 @@ mod tests {
         assert_eq!(true, res.value(2));
         assert_eq!(false, res.value(3));
     }
+
+    #[test]
+    fn test_is_null_on_concrete_arrays() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let res = is_null(&a).unwrap();
+        assert_eq!(0, res.null_count());
+        assert_eq!(
+            vec![Some(false), Some(true), Some(false)],
+            res.iter().collect::<Vec<_>>()
+        );
+
+        let a: ArrayRef = Arc::new(StringArray::from(vec![None, Some("b")]));
+        let res = is_not_null(&a).unwrap();
+        assert_eq!(
+            vec![Some(false), Some(true)],
+            res.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_null_array_is_null() {
+        let a: ArrayRef = Arc::new(NullArray::new(3));
+
+        let res = is_null(&a).unwrap();
+        assert_eq!(3, res.len());
+        assert_eq!(0, res.null_count());
+        assert!((0..3).all(|i| res.value(i)));
+
+        let res = is_not_null(&a).unwrap();
+        assert!((0..3).all(|i| !res.value(i)));
+    }
 }
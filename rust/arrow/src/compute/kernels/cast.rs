@@ -42,12 +42,32 @@ use crate::buffer::Buffer;
 use crate::compute::kernels::arithmetic::{divide, multiply};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::scalar::ScalarValue;
 use crate::{array::*, compute::take};
 
+/// CastOptions provides a way to override the default cast behaviors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastOptions {
+    /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
+    pub safe: bool,
+}
+
+/// The default cast options, which return null on cast failures
+pub const DEFAULT_CAST_OPTIONS: CastOptions = CastOptions { safe: true };
+
+impl Default for CastOptions {
+    fn default() -> Self {
+        DEFAULT_CAST_OPTIONS
+    }
+}
+
 /// Return true if a value of type `from_type` can be cast into a
 /// value of `to_type`. Note that such as cast may be lossy.
 ///
 /// If this function returns true to stay consistent with the `cast` kernel below.
+///
+/// Casts that only succeed for some values, such as List to FixedSizeList, are not
+/// reported, as they can't be decided from the types alone.
 pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     use self::DataType::*;
     if from_type == to_type {
@@ -58,6 +78,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (List(list_from), List(list_to)) => can_cast_types(list_from, list_to),
+        // depends on the lengths of the lists, see `cast_list_to_fixed_size_list`
+        (List(_), FixedSizeList(_, _)) => false,
         (List(_), _) => false,
         (_, List(list_to)) => can_cast_types(from_type, list_to),
         (Dictionary(_, from_value_type), Dictionary(_, to_value_type)) => {
@@ -66,6 +88,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
 
+        (Utf8, LargeUtf8) => true,
+        (LargeUtf8, Utf8) => true,
         (_, Boolean) => DataType::is_numeric(from_type),
         (Boolean, _) => DataType::is_numeric(to_type) || to_type == &Utf8,
         (Utf8, _) => DataType::is_numeric(to_type),
@@ -199,10 +223,17 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Timestamp(_, _)) => true,
         (Timestamp(_, _), Date32(_)) => true,
         (Timestamp(_, _), Date64(_)) => true,
+        (Date32(DateUnit::Day), Timestamp(_, _)) => true,
         // date64 to timestamp might not make sense,
 
         // end temporal casts
-        (_, _) => false,
+
+        // other pairs of boolean and numeric types, such as casts from null, go
+        // through `ScalarValue`
+        (_, Null) => false,
+        (_, _) => {
+            ScalarValue::supports_type(from_type) && ScalarValue::supports_type(to_type)
+        }
     }
 }
 
@@ -214,12 +245,23 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
-/// * List to List: the underlying data type is cast
+/// * Boolean to numeric: `true` returns 1, `false` returns 0
+/// * Utf8 to LargeUtf8: the offsets are widened, the values are not copied
+/// * LargeUtf8 to Utf8: values that do not fit in 32-bit offsets return null, or an
+///   error if `safe` is `false`
+/// * List to List: the underlying data type is cast, offsets and list validity are kept
 /// * Primitive to List: a list array with 1 value per slot is created
+/// * List to FixedSizeList: the offsets are dropped, lists whose length differs from the
+///   fixed size return an error
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Date32 to Timestamp: days are scaled to the unit of the timestamp, values that
+///   overflow return null, or an error if `safe` is `false`
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Any other pair of boolean and numeric types, or from null to one of them:
+///   element-wise through [`ScalarValue`], values that don't fit return null, or an
+///   error if `safe` is `false`
 ///
 /// Unsupported Casts
 /// * To or from `StructArray`
@@ -227,6 +269,20 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// * Utf8 to boolean
 /// * Interval and duration
 pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
+    cast_with_options(array, to_type, &DEFAULT_CAST_OPTIONS)
+}
+
+/// Cast `array` to the provided data type and return a new Array with
+/// type `to_type`, if possible, using the given `CastOptions`.
+///
+/// This behaves like `cast`, except that when `cast_options.safe` is `false`, numeric
+/// values that overflow the target type and strings that can't be parsed to numbers
+/// return an error instead of null.
+pub fn cast_with_options(
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
     use DataType::*;
     let from_type = array.data_type();
 
@@ -244,16 +300,13 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         (List(_), List(ref to)) => {
             let data = array.data_ref();
             let underlying_array = make_array(data.child_data()[0].clone());
-            let cast_array = cast(&underlying_array, &to)?;
+            let cast_array = cast_with_options(&underlying_array, &to, cast_options)?;
             let array_data = ArrayData::new(
-                *to.clone(),
+                to_type.clone(),
                 array.len(),
-                Some(cast_array.null_count()),
-                cast_array
-                    .data()
-                    .null_bitmap()
-                    .clone()
-                    .map(|bitmap| bitmap.bits),
+                Some(data.null_count()),
+                // reuse the list's validity
+                data.null_buffer().cloned(),
                 array.offset(),
                 // reuse offset buffer
                 data.buffers().to_vec(),
@@ -262,17 +315,20 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
             let list = ListArray::from(Arc::new(array_data));
             Ok(Arc::new(list) as ArrayRef)
         }
+        (List(_), FixedSizeList(ref to, size)) => {
+            cast_list_to_fixed_size_list(array, to, *size, cast_options)
+        }
         (List(_), _) => Err(ArrowError::ComputeError(
             "Cannot cast list to non-list data types".to_string(),
         )),
         (_, List(ref to)) => {
             // cast primitive to list's primitive
-            let cast_array = cast(array, &to)?;
+            let cast_array = cast_with_options(array, &to, cast_options)?;
             // create offsets, where if array.len() = 2, we have [0,1,2]
             let offsets: Vec<i32> = (0..=array.len() as i32).collect();
             let value_offsets = Buffer::from(offsets[..].to_byte_slice());
             let list_data = ArrayData::new(
-                *to.clone(),
+                to_type.clone(),
                 array.len(),
                 Some(cast_array.null_count()),
                 cast_array
@@ -289,33 +345,57 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
             Ok(list_array)
         }
         (Dictionary(index_type, _), _) => match **index_type {
-            DataType::Int8 => dictionary_cast::<Int8Type>(array, to_type),
-            DataType::Int16 => dictionary_cast::<Int16Type>(array, to_type),
-            DataType::Int32 => dictionary_cast::<Int32Type>(array, to_type),
-            DataType::Int64 => dictionary_cast::<Int64Type>(array, to_type),
-            DataType::UInt8 => dictionary_cast::<UInt8Type>(array, to_type),
-            DataType::UInt16 => dictionary_cast::<UInt16Type>(array, to_type),
-            DataType::UInt32 => dictionary_cast::<UInt32Type>(array, to_type),
-            DataType::UInt64 => dictionary_cast::<UInt64Type>(array, to_type),
+            DataType::Int8 => dictionary_cast::<Int8Type>(array, to_type, cast_options),
+            DataType::Int16 => dictionary_cast::<Int16Type>(array, to_type, cast_options),
+            DataType::Int32 => dictionary_cast::<Int32Type>(array, to_type, cast_options),
+            DataType::Int64 => dictionary_cast::<Int64Type>(array, to_type, cast_options),
+            DataType::UInt8 => dictionary_cast::<UInt8Type>(array, to_type, cast_options),
+            DataType::UInt16 => {
+                dictionary_cast::<UInt16Type>(array, to_type, cast_options)
+            }
+            DataType::UInt32 => {
+                dictionary_cast::<UInt32Type>(array, to_type, cast_options)
+            }
+            DataType::UInt64 => {
+                dictionary_cast::<UInt64Type>(array, to_type, cast_options)
+            }
             _ => Err(ArrowError::ComputeError(format!(
                 "Casting from dictionary type {:?} to {:?} not supported",
                 from_type, to_type,
             ))),
         },
         (_, Dictionary(index_type, value_type)) => match **index_type {
-            DataType::Int8 => cast_to_dictionary::<Int8Type>(array, value_type),
-            DataType::Int16 => cast_to_dictionary::<Int16Type>(array, value_type),
-            DataType::Int32 => cast_to_dictionary::<Int32Type>(array, value_type),
-            DataType::Int64 => cast_to_dictionary::<Int64Type>(array, value_type),
-            DataType::UInt8 => cast_to_dictionary::<UInt8Type>(array, value_type),
-            DataType::UInt16 => cast_to_dictionary::<UInt16Type>(array, value_type),
-            DataType::UInt32 => cast_to_dictionary::<UInt32Type>(array, value_type),
-            DataType::UInt64 => cast_to_dictionary::<UInt64Type>(array, value_type),
+            DataType::Int8 => {
+                cast_to_dictionary::<Int8Type>(array, value_type, cast_options)
+            }
+            DataType::Int16 => {
+                cast_to_dictionary::<Int16Type>(array, value_type, cast_options)
+            }
+            DataType::Int32 => {
+                cast_to_dictionary::<Int32Type>(array, value_type, cast_options)
+            }
+            DataType::Int64 => {
+                cast_to_dictionary::<Int64Type>(array, value_type, cast_options)
+            }
+            DataType::UInt8 => {
+                cast_to_dictionary::<UInt8Type>(array, value_type, cast_options)
+            }
+            DataType::UInt16 => {
+                cast_to_dictionary::<UInt16Type>(array, value_type, cast_options)
+            }
+            DataType::UInt32 => {
+                cast_to_dictionary::<UInt32Type>(array, value_type, cast_options)
+            }
+            DataType::UInt64 => {
+                cast_to_dictionary::<UInt64Type>(array, value_type, cast_options)
+            }
             _ => Err(ArrowError::ComputeError(format!(
                 "Casting from type {:?} to dictionary type {:?} not supported",
                 from_type, to_type,
             ))),
         },
+        (Utf8, LargeUtf8) => cast_utf8_to_large_utf8(array),
+        (LargeUtf8, Utf8) => cast_large_utf8_to_utf8(array, cast_options),
         (_, Boolean) => match from_type {
             UInt8 => cast_numeric_to_bool::<UInt8Type>(array),
             UInt16 => cast_numeric_to_bool::<UInt16Type>(array),
@@ -366,16 +446,16 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
             ))),
         },
         (Utf8, _) => match to_type {
-            UInt8 => cast_string_to_numeric::<UInt8Type>(array),
-            UInt16 => cast_string_to_numeric::<UInt16Type>(array),
-            UInt32 => cast_string_to_numeric::<UInt32Type>(array),
-            UInt64 => cast_string_to_numeric::<UInt64Type>(array),
-            Int8 => cast_string_to_numeric::<Int8Type>(array),
-            Int16 => cast_string_to_numeric::<Int16Type>(array),
-            Int32 => cast_string_to_numeric::<Int32Type>(array),
-            Int64 => cast_string_to_numeric::<Int64Type>(array),
-            Float32 => cast_string_to_numeric::<Float32Type>(array),
-            Float64 => cast_string_to_numeric::<Float64Type>(array),
+            UInt8 => cast_string_to_numeric::<UInt8Type>(array, cast_options),
+            UInt16 => cast_string_to_numeric::<UInt16Type>(array, cast_options),
+            UInt32 => cast_string_to_numeric::<UInt32Type>(array, cast_options),
+            UInt64 => cast_string_to_numeric::<UInt64Type>(array, cast_options),
+            Int8 => cast_string_to_numeric::<Int8Type>(array, cast_options),
+            Int16 => cast_string_to_numeric::<Int16Type>(array, cast_options),
+            Int32 => cast_string_to_numeric::<Int32Type>(array, cast_options),
+            Int64 => cast_string_to_numeric::<Int64Type>(array, cast_options),
+            Float32 => cast_string_to_numeric::<Float32Type>(array, cast_options),
+            Float64 => cast_string_to_numeric::<Float64Type>(array, cast_options),
             _ => Err(ArrowError::ComputeError(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -415,105 +495,269 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         },
 
         // start numeric casts
-        (UInt8, UInt16) => cast_numeric_arrays::<UInt8Type, UInt16Type>(array),
-        (UInt8, UInt32) => cast_numeric_arrays::<UInt8Type, UInt32Type>(array),
-        (UInt8, UInt64) => cast_numeric_arrays::<UInt8Type, UInt64Type>(array),
-        (UInt8, Int8) => cast_numeric_arrays::<UInt8Type, Int8Type>(array),
-        (UInt8, Int16) => cast_numeric_arrays::<UInt8Type, Int16Type>(array),
-        (UInt8, Int32) => cast_numeric_arrays::<UInt8Type, Int32Type>(array),
-        (UInt8, Int64) => cast_numeric_arrays::<UInt8Type, Int64Type>(array),
-        (UInt8, Float32) => cast_numeric_arrays::<UInt8Type, Float32Type>(array),
-        (UInt8, Float64) => cast_numeric_arrays::<UInt8Type, Float64Type>(array),
-
-        (UInt16, UInt8) => cast_numeric_arrays::<UInt16Type, UInt8Type>(array),
-        (UInt16, UInt32) => cast_numeric_arrays::<UInt16Type, UInt32Type>(array),
-        (UInt16, UInt64) => cast_numeric_arrays::<UInt16Type, UInt64Type>(array),
-        (UInt16, Int8) => cast_numeric_arrays::<UInt16Type, Int8Type>(array),
-        (UInt16, Int16) => cast_numeric_arrays::<UInt16Type, Int16Type>(array),
-        (UInt16, Int32) => cast_numeric_arrays::<UInt16Type, Int32Type>(array),
-        (UInt16, Int64) => cast_numeric_arrays::<UInt16Type, Int64Type>(array),
-        (UInt16, Float32) => cast_numeric_arrays::<UInt16Type, Float32Type>(array),
-        (UInt16, Float64) => cast_numeric_arrays::<UInt16Type, Float64Type>(array),
-
-        (UInt32, UInt8) => cast_numeric_arrays::<UInt32Type, UInt8Type>(array),
-        (UInt32, UInt16) => cast_numeric_arrays::<UInt32Type, UInt16Type>(array),
-        (UInt32, UInt64) => cast_numeric_arrays::<UInt32Type, UInt64Type>(array),
-        (UInt32, Int8) => cast_numeric_arrays::<UInt32Type, Int8Type>(array),
-        (UInt32, Int16) => cast_numeric_arrays::<UInt32Type, Int16Type>(array),
-        (UInt32, Int32) => cast_numeric_arrays::<UInt32Type, Int32Type>(array),
-        (UInt32, Int64) => cast_numeric_arrays::<UInt32Type, Int64Type>(array),
-        (UInt32, Float32) => cast_numeric_arrays::<UInt32Type, Float32Type>(array),
-        (UInt32, Float64) => cast_numeric_arrays::<UInt32Type, Float64Type>(array),
-
-        (UInt64, UInt8) => cast_numeric_arrays::<UInt64Type, UInt8Type>(array),
-        (UInt64, UInt16) => cast_numeric_arrays::<UInt64Type, UInt16Type>(array),
-        (UInt64, UInt32) => cast_numeric_arrays::<UInt64Type, UInt32Type>(array),
-        (UInt64, Int8) => cast_numeric_arrays::<UInt64Type, Int8Type>(array),
-        (UInt64, Int16) => cast_numeric_arrays::<UInt64Type, Int16Type>(array),
-        (UInt64, Int32) => cast_numeric_arrays::<UInt64Type, Int32Type>(array),
-        (UInt64, Int64) => cast_numeric_arrays::<UInt64Type, Int64Type>(array),
-        (UInt64, Float32) => cast_numeric_arrays::<UInt64Type, Float32Type>(array),
-        (UInt64, Float64) => cast_numeric_arrays::<UInt64Type, Float64Type>(array),
-
-        (Int8, UInt8) => cast_numeric_arrays::<Int8Type, UInt8Type>(array),
-        (Int8, UInt16) => cast_numeric_arrays::<Int8Type, UInt16Type>(array),
-        (Int8, UInt32) => cast_numeric_arrays::<Int8Type, UInt32Type>(array),
-        (Int8, UInt64) => cast_numeric_arrays::<Int8Type, UInt64Type>(array),
-        (Int8, Int16) => cast_numeric_arrays::<Int8Type, Int16Type>(array),
-        (Int8, Int32) => cast_numeric_arrays::<Int8Type, Int32Type>(array),
-        (Int8, Int64) => cast_numeric_arrays::<Int8Type, Int64Type>(array),
-        (Int8, Float32) => cast_numeric_arrays::<Int8Type, Float32Type>(array),
-        (Int8, Float64) => cast_numeric_arrays::<Int8Type, Float64Type>(array),
-
-        (Int16, UInt8) => cast_numeric_arrays::<Int16Type, UInt8Type>(array),
-        (Int16, UInt16) => cast_numeric_arrays::<Int16Type, UInt16Type>(array),
-        (Int16, UInt32) => cast_numeric_arrays::<Int16Type, UInt32Type>(array),
-        (Int16, UInt64) => cast_numeric_arrays::<Int16Type, UInt64Type>(array),
-        (Int16, Int8) => cast_numeric_arrays::<Int16Type, Int8Type>(array),
-        (Int16, Int32) => cast_numeric_arrays::<Int16Type, Int32Type>(array),
-        (Int16, Int64) => cast_numeric_arrays::<Int16Type, Int64Type>(array),
-        (Int16, Float32) => cast_numeric_arrays::<Int16Type, Float32Type>(array),
-        (Int16, Float64) => cast_numeric_arrays::<Int16Type, Float64Type>(array),
-
-        (Int32, UInt8) => cast_numeric_arrays::<Int32Type, UInt8Type>(array),
-        (Int32, UInt16) => cast_numeric_arrays::<Int32Type, UInt16Type>(array),
-        (Int32, UInt32) => cast_numeric_arrays::<Int32Type, UInt32Type>(array),
-        (Int32, UInt64) => cast_numeric_arrays::<Int32Type, UInt64Type>(array),
-        (Int32, Int8) => cast_numeric_arrays::<Int32Type, Int8Type>(array),
-        (Int32, Int16) => cast_numeric_arrays::<Int32Type, Int16Type>(array),
-        (Int32, Int64) => cast_numeric_arrays::<Int32Type, Int64Type>(array),
-        (Int32, Float32) => cast_numeric_arrays::<Int32Type, Float32Type>(array),
-        (Int32, Float64) => cast_numeric_arrays::<Int32Type, Float64Type>(array),
-
-        (Int64, UInt8) => cast_numeric_arrays::<Int64Type, UInt8Type>(array),
-        (Int64, UInt16) => cast_numeric_arrays::<Int64Type, UInt16Type>(array),
-        (Int64, UInt32) => cast_numeric_arrays::<Int64Type, UInt32Type>(array),
-        (Int64, UInt64) => cast_numeric_arrays::<Int64Type, UInt64Type>(array),
-        (Int64, Int8) => cast_numeric_arrays::<Int64Type, Int8Type>(array),
-        (Int64, Int16) => cast_numeric_arrays::<Int64Type, Int16Type>(array),
-        (Int64, Int32) => cast_numeric_arrays::<Int64Type, Int32Type>(array),
-        (Int64, Float32) => cast_numeric_arrays::<Int64Type, Float32Type>(array),
-        (Int64, Float64) => cast_numeric_arrays::<Int64Type, Float64Type>(array),
-
-        (Float32, UInt8) => cast_numeric_arrays::<Float32Type, UInt8Type>(array),
-        (Float32, UInt16) => cast_numeric_arrays::<Float32Type, UInt16Type>(array),
-        (Float32, UInt32) => cast_numeric_arrays::<Float32Type, UInt32Type>(array),
-        (Float32, UInt64) => cast_numeric_arrays::<Float32Type, UInt64Type>(array),
-        (Float32, Int8) => cast_numeric_arrays::<Float32Type, Int8Type>(array),
-        (Float32, Int16) => cast_numeric_arrays::<Float32Type, Int16Type>(array),
-        (Float32, Int32) => cast_numeric_arrays::<Float32Type, Int32Type>(array),
-        (Float32, Int64) => cast_numeric_arrays::<Float32Type, Int64Type>(array),
-        (Float32, Float64) => cast_numeric_arrays::<Float32Type, Float64Type>(array),
-
-        (Float64, UInt8) => cast_numeric_arrays::<Float64Type, UInt8Type>(array),
-        (Float64, UInt16) => cast_numeric_arrays::<Float64Type, UInt16Type>(array),
-        (Float64, UInt32) => cast_numeric_arrays::<Float64Type, UInt32Type>(array),
-        (Float64, UInt64) => cast_numeric_arrays::<Float64Type, UInt64Type>(array),
-        (Float64, Int8) => cast_numeric_arrays::<Float64Type, Int8Type>(array),
-        (Float64, Int16) => cast_numeric_arrays::<Float64Type, Int16Type>(array),
-        (Float64, Int32) => cast_numeric_arrays::<Float64Type, Int32Type>(array),
-        (Float64, Int64) => cast_numeric_arrays::<Float64Type, Int64Type>(array),
-        (Float64, Float32) => cast_numeric_arrays::<Float64Type, Float32Type>(array),
+        (UInt8, UInt16) => {
+            cast_numeric_arrays::<UInt8Type, UInt16Type>(array, cast_options)
+        }
+        (UInt8, UInt32) => {
+            cast_numeric_arrays::<UInt8Type, UInt32Type>(array, cast_options)
+        }
+        (UInt8, UInt64) => {
+            cast_numeric_arrays::<UInt8Type, UInt64Type>(array, cast_options)
+        }
+        (UInt8, Int8) => cast_numeric_arrays::<UInt8Type, Int8Type>(array, cast_options),
+        (UInt8, Int16) => {
+            cast_numeric_arrays::<UInt8Type, Int16Type>(array, cast_options)
+        }
+        (UInt8, Int32) => {
+            cast_numeric_arrays::<UInt8Type, Int32Type>(array, cast_options)
+        }
+        (UInt8, Int64) => {
+            cast_numeric_arrays::<UInt8Type, Int64Type>(array, cast_options)
+        }
+        (UInt8, Float32) => {
+            cast_numeric_arrays::<UInt8Type, Float32Type>(array, cast_options)
+        }
+        (UInt8, Float64) => {
+            cast_numeric_arrays::<UInt8Type, Float64Type>(array, cast_options)
+        }
+
+        (UInt16, UInt8) => {
+            cast_numeric_arrays::<UInt16Type, UInt8Type>(array, cast_options)
+        }
+        (UInt16, UInt32) => {
+            cast_numeric_arrays::<UInt16Type, UInt32Type>(array, cast_options)
+        }
+        (UInt16, UInt64) => {
+            cast_numeric_arrays::<UInt16Type, UInt64Type>(array, cast_options)
+        }
+        (UInt16, Int8) => {
+            cast_numeric_arrays::<UInt16Type, Int8Type>(array, cast_options)
+        }
+        (UInt16, Int16) => {
+            cast_numeric_arrays::<UInt16Type, Int16Type>(array, cast_options)
+        }
+        (UInt16, Int32) => {
+            cast_numeric_arrays::<UInt16Type, Int32Type>(array, cast_options)
+        }
+        (UInt16, Int64) => {
+            cast_numeric_arrays::<UInt16Type, Int64Type>(array, cast_options)
+        }
+        (UInt16, Float32) => {
+            cast_numeric_arrays::<UInt16Type, Float32Type>(array, cast_options)
+        }
+        (UInt16, Float64) => {
+            cast_numeric_arrays::<UInt16Type, Float64Type>(array, cast_options)
+        }
+
+        (UInt32, UInt8) => {
+            cast_numeric_arrays::<UInt32Type, UInt8Type>(array, cast_options)
+        }
+        (UInt32, UInt16) => {
+            cast_numeric_arrays::<UInt32Type, UInt16Type>(array, cast_options)
+        }
+        (UInt32, UInt64) => {
+            cast_numeric_arrays::<UInt32Type, UInt64Type>(array, cast_options)
+        }
+        (UInt32, Int8) => {
+            cast_numeric_arrays::<UInt32Type, Int8Type>(array, cast_options)
+        }
+        (UInt32, Int16) => {
+            cast_numeric_arrays::<UInt32Type, Int16Type>(array, cast_options)
+        }
+        (UInt32, Int32) => {
+            cast_numeric_arrays::<UInt32Type, Int32Type>(array, cast_options)
+        }
+        (UInt32, Int64) => {
+            cast_numeric_arrays::<UInt32Type, Int64Type>(array, cast_options)
+        }
+        (UInt32, Float32) => {
+            cast_numeric_arrays::<UInt32Type, Float32Type>(array, cast_options)
+        }
+        (UInt32, Float64) => {
+            cast_numeric_arrays::<UInt32Type, Float64Type>(array, cast_options)
+        }
+
+        (UInt64, UInt8) => {
+            cast_numeric_arrays::<UInt64Type, UInt8Type>(array, cast_options)
+        }
+        (UInt64, UInt16) => {
+            cast_numeric_arrays::<UInt64Type, UInt16Type>(array, cast_options)
+        }
+        (UInt64, UInt32) => {
+            cast_numeric_arrays::<UInt64Type, UInt32Type>(array, cast_options)
+        }
+        (UInt64, Int8) => {
+            cast_numeric_arrays::<UInt64Type, Int8Type>(array, cast_options)
+        }
+        (UInt64, Int16) => {
+            cast_numeric_arrays::<UInt64Type, Int16Type>(array, cast_options)
+        }
+        (UInt64, Int32) => {
+            cast_numeric_arrays::<UInt64Type, Int32Type>(array, cast_options)
+        }
+        (UInt64, Int64) => {
+            cast_numeric_arrays::<UInt64Type, Int64Type>(array, cast_options)
+        }
+        (UInt64, Float32) => {
+            cast_numeric_arrays::<UInt64Type, Float32Type>(array, cast_options)
+        }
+        (UInt64, Float64) => {
+            cast_numeric_arrays::<UInt64Type, Float64Type>(array, cast_options)
+        }
+
+        (Int8, UInt8) => cast_numeric_arrays::<Int8Type, UInt8Type>(array, cast_options),
+        (Int8, UInt16) => {
+            cast_numeric_arrays::<Int8Type, UInt16Type>(array, cast_options)
+        }
+        (Int8, UInt32) => {
+            cast_numeric_arrays::<Int8Type, UInt32Type>(array, cast_options)
+        }
+        (Int8, UInt64) => {
+            cast_numeric_arrays::<Int8Type, UInt64Type>(array, cast_options)
+        }
+        (Int8, Int16) => cast_numeric_arrays::<Int8Type, Int16Type>(array, cast_options),
+        (Int8, Int32) => cast_numeric_arrays::<Int8Type, Int32Type>(array, cast_options),
+        (Int8, Int64) => cast_numeric_arrays::<Int8Type, Int64Type>(array, cast_options),
+        (Int8, Float32) => {
+            cast_numeric_arrays::<Int8Type, Float32Type>(array, cast_options)
+        }
+        (Int8, Float64) => {
+            cast_numeric_arrays::<Int8Type, Float64Type>(array, cast_options)
+        }
+
+        (Int16, UInt8) => {
+            cast_numeric_arrays::<Int16Type, UInt8Type>(array, cast_options)
+        }
+        (Int16, UInt16) => {
+            cast_numeric_arrays::<Int16Type, UInt16Type>(array, cast_options)
+        }
+        (Int16, UInt32) => {
+            cast_numeric_arrays::<Int16Type, UInt32Type>(array, cast_options)
+        }
+        (Int16, UInt64) => {
+            cast_numeric_arrays::<Int16Type, UInt64Type>(array, cast_options)
+        }
+        (Int16, Int8) => cast_numeric_arrays::<Int16Type, Int8Type>(array, cast_options),
+        (Int16, Int32) => {
+            cast_numeric_arrays::<Int16Type, Int32Type>(array, cast_options)
+        }
+        (Int16, Int64) => {
+            cast_numeric_arrays::<Int16Type, Int64Type>(array, cast_options)
+        }
+        (Int16, Float32) => {
+            cast_numeric_arrays::<Int16Type, Float32Type>(array, cast_options)
+        }
+        (Int16, Float64) => {
+            cast_numeric_arrays::<Int16Type, Float64Type>(array, cast_options)
+        }
+
+        (Int32, UInt8) => {
+            cast_numeric_arrays::<Int32Type, UInt8Type>(array, cast_options)
+        }
+        (Int32, UInt16) => {
+            cast_numeric_arrays::<Int32Type, UInt16Type>(array, cast_options)
+        }
+        (Int32, UInt32) => {
+            cast_numeric_arrays::<Int32Type, UInt32Type>(array, cast_options)
+        }
+        (Int32, UInt64) => {
+            cast_numeric_arrays::<Int32Type, UInt64Type>(array, cast_options)
+        }
+        (Int32, Int8) => cast_numeric_arrays::<Int32Type, Int8Type>(array, cast_options),
+        (Int32, Int16) => {
+            cast_numeric_arrays::<Int32Type, Int16Type>(array, cast_options)
+        }
+        (Int32, Int64) => {
+            cast_numeric_arrays::<Int32Type, Int64Type>(array, cast_options)
+        }
+        (Int32, Float32) => {
+            cast_numeric_arrays::<Int32Type, Float32Type>(array, cast_options)
+        }
+        (Int32, Float64) => {
+            cast_numeric_arrays::<Int32Type, Float64Type>(array, cast_options)
+        }
+
+        (Int64, UInt8) => {
+            cast_numeric_arrays::<Int64Type, UInt8Type>(array, cast_options)
+        }
+        (Int64, UInt16) => {
+            cast_numeric_arrays::<Int64Type, UInt16Type>(array, cast_options)
+        }
+        (Int64, UInt32) => {
+            cast_numeric_arrays::<Int64Type, UInt32Type>(array, cast_options)
+        }
+        (Int64, UInt64) => {
+            cast_numeric_arrays::<Int64Type, UInt64Type>(array, cast_options)
+        }
+        (Int64, Int8) => cast_numeric_arrays::<Int64Type, Int8Type>(array, cast_options),
+        (Int64, Int16) => {
+            cast_numeric_arrays::<Int64Type, Int16Type>(array, cast_options)
+        }
+        (Int64, Int32) => {
+            cast_numeric_arrays::<Int64Type, Int32Type>(array, cast_options)
+        }
+        (Int64, Float32) => {
+            cast_numeric_arrays::<Int64Type, Float32Type>(array, cast_options)
+        }
+        (Int64, Float64) => {
+            cast_numeric_arrays::<Int64Type, Float64Type>(array, cast_options)
+        }
+
+        (Float32, UInt8) => {
+            cast_numeric_arrays::<Float32Type, UInt8Type>(array, cast_options)
+        }
+        (Float32, UInt16) => {
+            cast_numeric_arrays::<Float32Type, UInt16Type>(array, cast_options)
+        }
+        (Float32, UInt32) => {
+            cast_numeric_arrays::<Float32Type, UInt32Type>(array, cast_options)
+        }
+        (Float32, UInt64) => {
+            cast_numeric_arrays::<Float32Type, UInt64Type>(array, cast_options)
+        }
+        (Float32, Int8) => {
+            cast_numeric_arrays::<Float32Type, Int8Type>(array, cast_options)
+        }
+        (Float32, Int16) => {
+            cast_numeric_arrays::<Float32Type, Int16Type>(array, cast_options)
+        }
+        (Float32, Int32) => {
+            cast_numeric_arrays::<Float32Type, Int32Type>(array, cast_options)
+        }
+        (Float32, Int64) => {
+            cast_numeric_arrays::<Float32Type, Int64Type>(array, cast_options)
+        }
+        (Float32, Float64) => {
+            cast_numeric_arrays::<Float32Type, Float64Type>(array, cast_options)
+        }
+
+        (Float64, UInt8) => {
+            cast_numeric_arrays::<Float64Type, UInt8Type>(array, cast_options)
+        }
+        (Float64, UInt16) => {
+            cast_numeric_arrays::<Float64Type, UInt16Type>(array, cast_options)
+        }
+        (Float64, UInt32) => {
+            cast_numeric_arrays::<Float64Type, UInt32Type>(array, cast_options)
+        }
+        (Float64, UInt64) => {
+            cast_numeric_arrays::<Float64Type, UInt64Type>(array, cast_options)
+        }
+        (Float64, Int8) => {
+            cast_numeric_arrays::<Float64Type, Int8Type>(array, cast_options)
+        }
+        (Float64, Int16) => {
+            cast_numeric_arrays::<Float64Type, Int16Type>(array, cast_options)
+        }
+        (Float64, Int32) => {
+            cast_numeric_arrays::<Float64Type, Int32Type>(array, cast_options)
+        }
+        (Float64, Int64) => {
+            cast_numeric_arrays::<Float64Type, Int64Type>(array, cast_options)
+        }
+        (Float64, Float32) => {
+            cast_numeric_arrays::<Float64Type, Float32Type>(array, cast_options)
+        }
         // end numeric casts
 
         // temporal casts
@@ -745,9 +989,41 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
                 cast_array_data::<Date64Type>(array, to_type.clone())
             }
         }
+        (Date32(DateUnit::Day), Timestamp(to_unit, _)) => {
+            let date_array = array.as_any().downcast_ref::<Date32Array>().unwrap();
+            let to_size = time_unit_multiple(&to_unit) * SECONDS_IN_DAY;
+            let mut b = Int64Builder::new(array.len());
+            for i in 0..array.len() {
+                if array.is_null(i) {
+                    b.append_null()?;
+                    continue;
+                }
+                let days = date_array.value(i) as i64;
+                match days.checked_mul(to_size) {
+                    Some(v) => b.append_value(v)?,
+                    None if cast_options.safe => b.append_null()?,
+                    None => {
+                        return Err(ArrowError::ComputeError(format!(
+                            "Casting {} days to {:?} overflows",
+                            days, to_type
+                        )))
+                    }
+                }
+            }
+
+            let time_array = Arc::new(b.finish()) as ArrayRef;
+            cast_with_options(&time_array, to_type, cast_options)
+        }
         // date64 to timestamp might not make sense,
 
         // end temporal casts
+        (_, _)
+            if to_type != &Null
+                && ScalarValue::supports_type(from_type)
+                && ScalarValue::supports_type(to_type) =>
+        {
+            cast_via_scalars(array, to_type, cast_options)
+        }
         (_, _) => Err(ArrowError::ComputeError(format!(
             "Casting from {:?} to {:?} not supported",
             from_type, to_type,
@@ -755,6 +1031,28 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
     }
 }
 
+/// Casts `array` one value at a time by converting each value to a [`ScalarValue`].
+///
+/// This is much slower than the dedicated casts and is only used for pairs of types
+/// that don't have one.
+fn cast_via_scalars(
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let scalars = (0..array.len())
+        .map(|i| {
+            let value = ScalarValue::try_from_array(array.as_ref(), i)?;
+            match value.cast_to(to_type) {
+                Ok(value) => Ok(value),
+                Err(_) if cast_options.safe => Ok(ScalarValue::Null),
+                Err(e) => Err(e),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ScalarValue::iter_to_array(to_type, scalars)
+}
+
 /// Get the time unit as a multiple of a second
 fn time_unit_multiple(unit: &TimeUnit) -> i64 {
     match unit {
@@ -798,18 +1096,25 @@ where
 }
 
 /// Convert Array into a PrimitiveArray of type, and apply numeric cast
-fn cast_numeric_arrays<FROM, TO>(from: &ArrayRef) -> Result<ArrayRef>
+fn cast_numeric_arrays<FROM, TO>(
+    from: &ArrayRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
 where
     FROM: ArrowNumericType,
     TO: ArrowNumericType,
     FROM::Native: num::NumCast,
     TO::Native: num::NumCast,
 {
-    Ok(Arc::new(numeric_cast::<FROM, TO>(
-        from.as_any()
-            .downcast_ref::<PrimitiveArray<FROM>>()
-            .unwrap(),
-    )))
+    let from = from
+        .as_any()
+        .downcast_ref::<PrimitiveArray<FROM>>()
+        .unwrap();
+    if cast_options.safe {
+        Ok(Arc::new(numeric_cast::<FROM, TO>(from)))
+    } else {
+        Ok(Arc::new(try_numeric_cast::<FROM, TO>(from)?))
+    }
 }
 
 /// Natural cast between numeric types
@@ -828,6 +1133,33 @@ where
         .collect()
 }
 
+/// Checked cast between numeric types, returning an error if a value does not fit in
+/// the target type
+fn try_numeric_cast<T, R>(from: &PrimitiveArray<T>) -> Result<PrimitiveArray<R>>
+where
+    T: ArrowNumericType,
+    R: ArrowNumericType,
+    T::Native: num::NumCast,
+    R::Native: num::NumCast,
+{
+    from.iter()
+        .map(|v| match v {
+            Some(v) => num::cast::cast::<T::Native, R::Native>(v)
+                .map(Some)
+                .ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "Cannot cast value {:?} of type {:?} to {:?}",
+                        v,
+                        T::DATA_TYPE,
+                        R::DATA_TYPE
+                    ))
+                }),
+            None => Ok(None),
+        })
+        .collect::<Result<Vec<Option<R::Native>>>>()
+        .map(|values| values.into_iter().collect())
+}
+
 /// Cast numeric types to Utf8
 fn cast_numeric_to_string<FROM>(array: &ArrayRef) -> Result<ArrayRef>
 where
@@ -862,31 +1194,191 @@ where
 }
 
 /// Cast numeric types to Utf8
-fn cast_string_to_numeric<TO>(from: &ArrayRef) -> Result<ArrayRef>
+fn cast_string_to_numeric<TO>(
+    from: &ArrayRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
 where
     TO: ArrowNumericType,
 {
     Ok(Arc::new(string_to_numeric_cast::<TO>(
         from.as_any().downcast_ref::<StringArray>().unwrap(),
-    )))
+        cast_options,
+    )?))
 }
 
-fn string_to_numeric_cast<T>(from: &StringArray) -> PrimitiveArray<T>
+fn string_to_numeric_cast<T>(
+    from: &StringArray,
+    cast_options: &CastOptions,
+) -> Result<PrimitiveArray<T>>
 where
     T: ArrowNumericType,
 {
     (0..from.len())
         .map(|i| {
             if from.is_null(i) {
-                None
+                Ok(None)
             } else {
                 match from.value(i).parse::<T::Native>() {
-                    Ok(v) => Some(v),
-                    Err(_) => None,
+                    Ok(v) => Ok(Some(v)),
+                    Err(_) if cast_options.safe => Ok(None),
+                    Err(_) => Err(ArrowError::ComputeError(format!(
+                        "Cannot cast string '{}' to value of {:?} type",
+                        from.value(i),
+                        T::DATA_TYPE
+                    ))),
                 }
             }
         })
-        .collect()
+        .collect::<Result<Vec<Option<T::Native>>>>()
+        .map(|values| values.into_iter().collect())
+}
+
+/// Cast `Utf8` to `LargeUtf8`, widening the offsets to 64 bits.
+///
+/// The values buffer is shared with the input array.
+fn cast_utf8_to_large_utf8(array: &ArrayRef) -> Result<ArrayRef> {
+    let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+    let offsets: Vec<i64> = (0..=array.len())
+        .map(|i| array.value_offset(i) as i64)
+        .collect();
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let data = ArrayData::new(
+        DataType::LargeUtf8,
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(offsets.to_byte_slice()), array.value_data()],
+        vec![],
+    );
+    Ok(make_array(Arc::new(data)))
+}
+
+/// Cast `LargeUtf8` to `Utf8`, narrowing the offsets to 32 bits.
+///
+/// The offsets are rebased to the first value of the array, so that the values
+/// buffer can be shared with the input array. Values ending past `i32::MAX` bytes
+/// return null, or an error if `cast_options.safe` is `false`.
+fn cast_large_utf8_to_utf8(
+    array: &ArrayRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+    let len = array.len();
+    let start = array.value_offset(0);
+
+    // offsets are increasing, so the values that fit are a prefix of the array
+    let fitting = (0..len)
+        .take_while(|&i| array.value_offset(i + 1) - start <= i32::MAX as i64)
+        .count();
+    if fitting < len && !cast_options.safe {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot cast LargeUtf8 to Utf8: value {} ends at offset {}, which exceeds i32::MAX",
+            fitting,
+            array.value_offset(fitting + 1) - start
+        )));
+    }
+
+    let mut offsets: Vec<i32> = Vec::with_capacity(len + 1);
+    offsets.push(0);
+    offsets.extend((0..len).map(|i| {
+        // values that do not fit are empty
+        let end = array.value_offset(if i < fitting { i + 1 } else { fitting });
+        (end - start) as i32
+    }));
+
+    let null_bit_buffer = if fitting < len {
+        let mut null_buf = BooleanBufferBuilder::new(len);
+        for i in 0..len {
+            null_buf.append(i < fitting && array.is_valid(i))?;
+        }
+        Some(null_buf.finish())
+    } else {
+        array
+            .data_ref()
+            .null_buffer()
+            .map(|b| b.bit_slice(array.offset(), len))
+    };
+
+    let data = ArrayData::new(
+        DataType::Utf8,
+        len,
+        None,
+        null_bit_buffer,
+        0,
+        vec![
+            Buffer::from(offsets.to_byte_slice()),
+            array.value_data().slice(start as usize),
+        ],
+        vec![],
+    );
+    Ok(make_array(Arc::new(data)))
+}
+
+/// Cast a `ListArray` to a `FixedSizeListArray` of `size` values of type `to`.
+///
+/// The values of each list are copied so that null slots take up `size` values, and the
+/// offsets buffer is dropped. Returns an error if a valid list does not have `size`
+/// values, whether or not the cast is `safe`.
+fn cast_list_to_fixed_size_list(
+    array: &ArrayRef,
+    to: &DataType,
+    size: i32,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    if size < 0 {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot cast to FixedSizeList of negative size {}",
+            size
+        )));
+    }
+    let array = array.as_any().downcast_ref::<ListArray>().unwrap();
+    let len = array.len();
+    let capacity = len.checked_mul(size as usize).ok_or_else(|| {
+        ArrowError::ComputeError(format!(
+            "Cannot cast {} lists to FixedSizeList of size {}: too many values",
+            len, size
+        ))
+    })?;
+
+    let mut null_buf = BooleanBufferBuilder::new(len);
+    let mut indices: Vec<Option<u32>> = Vec::with_capacity(capacity);
+    for i in 0..len {
+        let valid = array.is_valid(i);
+        if valid && array.value_length(i) != size {
+            return Err(ArrowError::ComputeError(format!(
+                "Cannot cast list of length {} at index {} to FixedSizeList of size {}",
+                array.value_length(i),
+                i,
+                size
+            )));
+        }
+        null_buf.append(valid)?;
+        if valid {
+            let start = array.value_offset(i) as u32;
+            indices.extend((start..start + size as u32).map(Some));
+        } else {
+            indices.extend(std::iter::repeat(None).take(size as usize));
+        }
+    }
+
+    let values = take(&array.values(), &UInt32Array::from(indices), None)?;
+    let values = cast_with_options(&values, to, cast_options)?;
+    let data = ArrayData::new(
+        DataType::FixedSizeList(Box::new(to.clone()), size),
+        len,
+        None,
+        Some(null_buf.finish()),
+        0,
+        vec![],
+        vec![values.data()],
+    );
+    Ok(make_array(Arc::new(data)))
 }
 
 /// Cast numeric types to Boolean
@@ -962,6 +1454,7 @@ where
 fn dictionary_cast<K: ArrowDictionaryKeyType>(
     array: &ArrayRef,
     to_type: &DataType,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef> {
     use DataType::*;
 
@@ -976,10 +1469,30 @@ fn dictionary_cast<K: ArrowDictionaryKeyType>(
                     )
                 })?;
 
-            let keys_array: ArrayRef = Arc::new(dict_array.keys_array());
             let values_array: ArrayRef = dict_array.values();
+
+            // if only the value type changes, keep the keys as they are and only
+            // cast the dictionary values
+            if **to_index_type == K::DATA_TYPE {
+                let cast_values =
+                    cast_with_options(&values_array, to_value_type, cast_options)?;
+                let data = dict_array.data_ref();
+                let data = Arc::new(ArrayData::new(
+                    to_type.clone(),
+                    data.len(),
+                    Some(data.null_count()),
+                    data.null_buffer().cloned(),
+                    data.offset(),
+                    data.buffers().to_vec(),
+                    vec![cast_values.data()],
+                ));
+                return Ok(Arc::new(DictionaryArray::<K>::from(data)));
+            }
+
+            let keys_array: ArrayRef = Arc::new(dict_array.keys_array());
             let cast_keys = cast(&keys_array, to_index_type)?;
-            let cast_values = cast(&values_array, to_value_type)?;
+            let cast_values =
+                cast_with_options(&values_array, to_value_type, cast_options)?;
 
             // Failure to cast keys (because they don't fit in the
             // target type) results in NULL values;
@@ -1027,12 +1540,16 @@ fn dictionary_cast<K: ArrowDictionaryKeyType>(
 
             Ok(new_array)
         }
-        _ => unpack_dictionary::<K>(array, to_type),
+        _ => unpack_dictionary::<K>(array, to_type, cast_options),
     }
 }
 
 // Unpack a dictionary where the keys are of type <K> into a flattened array of type to_type
-fn unpack_dictionary<K>(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef>
+fn unpack_dictionary<K>(
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
 where
     K: ArrowDictionaryKeyType,
 {
@@ -1047,7 +1564,8 @@ where
 
     // attempt to cast the dict values to the target type
     // use the take kernel to expand out the dictionary
-    let cast_dict_values = cast(&dict_array.values(), to_type)?;
+    let cast_dict_values =
+        cast_with_options(&dict_array.values(), to_type, cast_options)?;
 
     // Note take requires first casting the indicies to u32
     let keys_array: ArrayRef = Arc::new(dict_array.keys_array());
@@ -1072,19 +1590,52 @@ where
 fn cast_to_dictionary<K: ArrowDictionaryKeyType>(
     array: &ArrayRef,
     dict_value_type: &DataType,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef> {
     use DataType::*;
 
     match *dict_value_type {
-        Int8 => pack_numeric_to_dictionary::<K, Int8Type>(array, dict_value_type),
-        Int16 => pack_numeric_to_dictionary::<K, Int16Type>(array, dict_value_type),
-        Int32 => pack_numeric_to_dictionary::<K, Int32Type>(array, dict_value_type),
-        Int64 => pack_numeric_to_dictionary::<K, Int64Type>(array, dict_value_type),
-        UInt8 => pack_numeric_to_dictionary::<K, UInt8Type>(array, dict_value_type),
-        UInt16 => pack_numeric_to_dictionary::<K, UInt16Type>(array, dict_value_type),
-        UInt32 => pack_numeric_to_dictionary::<K, UInt32Type>(array, dict_value_type),
-        UInt64 => pack_numeric_to_dictionary::<K, UInt64Type>(array, dict_value_type),
-        Utf8 => pack_string_to_dictionary::<K>(array),
+        Int8 => pack_numeric_to_dictionary::<K, Int8Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        Int16 => pack_numeric_to_dictionary::<K, Int16Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        Int32 => pack_numeric_to_dictionary::<K, Int32Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        Int64 => pack_numeric_to_dictionary::<K, Int64Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        UInt8 => pack_numeric_to_dictionary::<K, UInt8Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        UInt16 => pack_numeric_to_dictionary::<K, UInt16Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        UInt32 => pack_numeric_to_dictionary::<K, UInt32Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        UInt64 => pack_numeric_to_dictionary::<K, UInt64Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        Utf8 => pack_string_to_dictionary::<K>(array, cast_options),
         _ => Err(ArrowError::ComputeError(format!(
             "Internal Error: Unsupported output type for dictionary packing: {:?}",
             dict_value_type
@@ -1097,13 +1648,14 @@ fn cast_to_dictionary<K: ArrowDictionaryKeyType>(
 fn pack_numeric_to_dictionary<K, V>(
     array: &ArrayRef,
     dict_value_type: &DataType,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef>
 where
     K: ArrowDictionaryKeyType,
     V: ArrowNumericType,
 {
     // attempt to cast the source array values to the target value type (the dictionary values type)
-    let cast_values = cast(array, &dict_value_type)?;
+    let cast_values = cast_with_options(array, &dict_value_type, cast_options)?;
     let values = cast_values
         .as_any()
         .downcast_ref::<PrimitiveArray<V>>()
@@ -1126,11 +1678,14 @@ where
 
 // Packs the data as a StringDictionaryArray, if possible, with the
 // key types of K
-fn pack_string_to_dictionary<K>(array: &ArrayRef) -> Result<ArrayRef>
+fn pack_string_to_dictionary<K>(
+    array: &ArrayRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
 where
     K: ArrowDictionaryKeyType,
 {
-    let cast_values = cast(array, &DataType::Utf8)?;
+    let cast_values = cast_with_options(array, &DataType::Utf8, cast_options)?;
     let values = cast_values.as_any().downcast_ref::<StringArray>().unwrap();
 
     let keys_builder = PrimitiveBuilder::<K>::new(values.len());
@@ -1180,6 +1735,115 @@ mod tests {
         assert_eq!(false, c.is_valid(4));
     }
 
+    #[test]
+    fn test_cast_i64_to_i8_with_options() {
+        let a = Int64Array::from(vec![300, 5]);
+        let array = Arc::new(a) as ArrayRef;
+
+        let options = CastOptions { safe: true };
+        let b = cast_with_options(&array, &DataType::Int8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Int8Array>().unwrap();
+        assert_eq!(2, c.len());
+        assert!(c.is_null(0));
+        assert_eq!(5, c.value(1));
+
+        let options = CastOptions { safe: false };
+        assert!(cast_with_options(&array, &DataType::Int8, &options).is_err());
+
+        let array = array.slice(1, 1);
+        let b = cast_with_options(&array, &DataType::Int8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Int8Array>().unwrap();
+        assert_eq!(5, c.value(0));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_large_utf8() {
+        let array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("hello"),
+            None,
+            Some("arrow"),
+            Some(""),
+        ]));
+        let b = cast(&array, &DataType::LargeUtf8).unwrap();
+        let c = b.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(4, c.len());
+        assert_eq!(1, c.null_count());
+        assert_eq!("hello", c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!("arrow", c.value(2));
+        assert_eq!("", c.value(3));
+        // the values are not copied
+        assert_eq!(
+            array.data().buffers()[1].raw_data(),
+            c.value_data().raw_data()
+        );
+
+        // and back again
+        let d = cast(&b, &DataType::Utf8).unwrap();
+        let d = d.as_any().downcast_ref::<StringArray>().unwrap();
+        let expected = array.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(expected, d);
+    }
+
+    #[test]
+    fn test_cast_sliced_large_utf8_to_utf8() {
+        let array =
+            LargeStringArray::from(vec![Some("a"), Some("bc"), None, Some("def")]);
+        let array = array.slice(1, 3);
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(3, c.len());
+        assert_eq!(1, c.null_count());
+        assert_eq!("bc", c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!("def", c.value(2));
+        // the offsets are rebased to the first value
+        assert_eq!(0, c.value_offset(0));
+        assert_eq!(5, c.value_offset(3));
+    }
+
+    #[test]
+    fn test_cast_large_utf8_to_utf8_offset_overflow() {
+        // synthetic array whose last value would end past i32::MAX; the cast only
+        // reads the offsets, so the values buffer does not need to be that large
+        let offsets: Vec<i64> = vec![0, 2, 3, i32::MAX as i64 + 4];
+        let data = ArrayData::builder(DataType::LargeUtf8)
+            .len(3)
+            .add_buffer(Buffer::from(offsets.to_byte_slice()))
+            .add_buffer(Buffer::from(b"abc"))
+            .build();
+        let array: ArrayRef = Arc::new(LargeStringArray::from(data));
+
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(3, c.len());
+        assert_eq!(1, c.null_count());
+        assert_eq!("ab", c.value(0));
+        assert_eq!("c", c.value(1));
+        assert!(c.is_null(2));
+        assert_eq!(0, c.value_length(2));
+
+        let options = CastOptions { safe: false };
+        let result = cast_with_options(&array, &DataType::Utf8, &options);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cast_utf8_to_i32_with_options() {
+        let a = StringArray::from(vec![Some("5"), None, Some("seven")]);
+        let array = Arc::new(a) as ArrayRef;
+
+        let b =
+            cast_with_options(&array, &DataType::Int32, &DEFAULT_CAST_OPTIONS).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(5, c.value(0));
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+
+        let options = CastOptions { safe: false };
+        assert!(cast_with_options(&array, &DataType::Int32, &options).is_err());
+    }
+
     #[test]
     fn test_cast_i32_to_u8_sliced() {
         let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
@@ -1216,6 +1880,7 @@ mod tests {
         let array = Arc::new(a) as ArrayRef;
         let b = cast(&array, &DataType::List(Box::new(DataType::Int32))).unwrap();
         assert_eq!(5, b.len());
+        assert_eq!(&DataType::List(Box::new(DataType::Int32)), b.data_type());
         let arr = b.as_any().downcast_ref::<ListArray>().unwrap();
         assert_eq!(0, arr.value_offset(0));
         assert_eq!(1, arr.value_offset(1));
@@ -1314,6 +1979,48 @@ mod tests {
         assert_eq!(false, c.is_valid(2));
     }
 
+    #[test]
+    fn test_cast_numeric_to_bool_semantics() {
+        let array: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(0), Some(1), Some(-5), None]));
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(
+            &BooleanArray::from(vec![Some(false), Some(true), Some(true), None]),
+            c
+        );
+
+        // negative zero is zero
+        let array: ArrayRef = Arc::new(Float64Array::from(vec![0.0, -0.0, 0.5]));
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(&BooleanArray::from(vec![false, false, true]), c);
+    }
+
+    #[test]
+    fn test_cast_i32_to_bool() {
+        let a = Int32Array::from(vec![Some(0), Some(2), None]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(false, c.value(0));
+        assert_eq!(true, c.value(1));
+        assert_eq!(false, c.is_valid(2));
+    }
+
+    #[test]
+    fn test_cast_bool_to_i32_round_trip() {
+        let a = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Int32).unwrap();
+        let c = cast(&b, &DataType::Boolean).unwrap();
+        let c = c.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(1, c.null_count());
+        assert_eq!(true, c.value(0));
+        assert_eq!(false, c.value(1));
+        assert_eq!(false, c.is_valid(2));
+    }
+
     #[test]
     fn test_cast_bool_to_f64() {
         let a = BooleanArray::from(vec![Some(true), Some(false), None]);
@@ -1353,9 +2060,12 @@ mod tests {
 
         let cast_array =
             cast(&list_array, &DataType::List(Box::new(DataType::UInt16))).unwrap();
-        // 3 negative values should get lost when casting to unsigned,
-        // 1 value should overflow
-        assert_eq!(4, cast_array.null_count());
+        assert_eq!(
+            &DataType::List(Box::new(DataType::UInt16)),
+            cast_array.data_type()
+        );
+        // the lists themselves stay valid, nulls only appear in the values
+        assert_eq!(0, cast_array.null_count());
         // offsets should be the same
         assert_eq!(
             list_array.data().buffers().to_vec(),
@@ -1367,6 +2077,8 @@ mod tests {
             .downcast_ref::<ListArray>()
             .unwrap();
         assert_eq!(DataType::UInt16, array.value_type());
+        // 3 negative values should get lost when casting to unsigned,
+        // 1 value should overflow
         assert_eq!(4, array.values().null_count());
         assert_eq!(3, array.value_length(0));
         assert_eq!(3, array.value_length(1));
@@ -1386,6 +2098,109 @@ mod tests {
         assert_eq!(false, u16arr.is_valid(7));
     }
 
+    #[test]
+    fn test_cast_list_i32_to_list_i64() {
+        let mut builder = ListBuilder::new(Int32Builder::new(5));
+        builder.values().append_slice(&[1, 2]).unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder
+            .values()
+            .append_slice(&[3, i32::MAX, i32::MIN])
+            .unwrap();
+        builder.append(true).unwrap();
+        let list_array = Arc::new(builder.finish()) as ArrayRef;
+
+        let cast_array =
+            cast(&list_array, &DataType::List(Box::new(DataType::Int64))).unwrap();
+        assert_eq!(
+            &DataType::List(Box::new(DataType::Int64)),
+            cast_array.data_type()
+        );
+        assert_eq!(3, cast_array.len());
+        assert_eq!(1, cast_array.null_count());
+        assert!(cast_array.is_null(1));
+        // offsets are untouched
+        assert_eq!(
+            list_array.data().buffers().to_vec(),
+            cast_array.data().buffers().to_vec()
+        );
+
+        let array = cast_array.as_any().downcast_ref::<ListArray>().unwrap();
+        let values = array.values();
+        let values = values.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(
+            &[1, 2, 3, i32::MAX as i64, i32::MIN as i64],
+            values.value_slice(0, 5)
+        );
+        let last = array.value(2);
+        let last = last.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(3, last.len());
+        assert_eq!(i32::MAX as i64, last.value(1));
+    }
+
+    #[test]
+    fn test_cast_list_i32_to_list_i64_nested_nulls() {
+        // [[1, null], null, [3]]
+        let mut builder = ListBuilder::new(Int32Builder::new(3));
+        builder.values().append_value(1).unwrap();
+        builder.values().append_null().unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.values().append_value(3).unwrap();
+        builder.append(true).unwrap();
+        let list_array = Arc::new(builder.finish()) as ArrayRef;
+
+        let cast_array =
+            cast(&list_array, &DataType::List(Box::new(DataType::Int64))).unwrap();
+        // the list validity is unchanged
+        assert_eq!(1, cast_array.null_count());
+        assert_eq!(
+            list_array.data().null_buffer(),
+            cast_array.data().null_buffer()
+        );
+        assert!(cast_array.is_valid(0));
+        assert!(cast_array.is_null(1));
+        assert!(cast_array.is_valid(2));
+
+        // the child validity is unchanged
+        let array = cast_array.as_any().downcast_ref::<ListArray>().unwrap();
+        let values = array.values();
+        let values = values.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(3, values.len());
+        assert_eq!(1, values.null_count());
+        assert_eq!(1, values.value(0));
+        assert!(values.is_null(1));
+        assert_eq!(3, values.value(2));
+
+        let first = array.value(0);
+        let first = first.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(2, first.len());
+        assert!(first.is_null(1));
+    }
+
+    #[test]
+    fn test_cast_list_sliced() {
+        let mut builder = ListBuilder::new(Int32Builder::new(5));
+        builder.values().append_slice(&[1, 2]).unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.values().append_slice(&[3]).unwrap();
+        builder.append(true).unwrap();
+        let list_array = Arc::new(builder.finish()) as ArrayRef;
+        let list_array = list_array.slice(1, 2);
+
+        let cast_array =
+            cast(&list_array, &DataType::List(Box::new(DataType::Int64))).unwrap();
+        assert_eq!(2, cast_array.len());
+        assert_eq!(1, cast_array.null_count());
+        assert!(cast_array.is_null(0));
+        let array = cast_array.as_any().downcast_ref::<ListArray>().unwrap();
+        let value = array.value(1);
+        let value = value.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(&[3], value.value_slice(0, 1));
+    }
+
     #[test]
     #[should_panic(
         expected = "Casting from Int32 to Timestamp(Microsecond, None) not supported"
@@ -1423,6 +2238,98 @@ mod tests {
         assert_eq!(1545696000000, c.value(1));
     }
 
+    #[test]
+    fn test_cast_via_scalars() {
+        // casts from null have no dedicated kernel, so they go through `ScalarValue`
+        let array = Arc::new(NullArray::new(3)) as ArrayRef;
+        let options = [DEFAULT_CAST_OPTIONS, CastOptions { safe: false }];
+        for to_type in &[DataType::Int8, DataType::UInt64, DataType::Float32] {
+            for options in &options {
+                let b = cast_with_options(&array, to_type, options).unwrap();
+                assert_eq!(to_type, b.data_type());
+                assert_eq!(3, b.len());
+                assert_eq!(3, b.null_count());
+            }
+        }
+
+        // values come through the fallback unchanged, as with the dedicated cast
+        let array = Arc::new(UInt8Array::from(vec![Some(1), None, Some(3)])) as ArrayRef;
+        let options = CastOptions { safe: false };
+        let b = cast_via_scalars(&array, &DataType::Float64, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(&Float64Array::from(vec![Some(1.0), None, Some(3.0)]), c);
+        let direct = cast_with_options(&array, &DataType::Float64, &options).unwrap();
+        let direct = direct.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(direct, c);
+
+        // values that don't fit are null, or an error if the cast is not safe
+        let array =
+            Arc::new(UInt8Array::from(vec![Some(1), None, Some(255)])) as ArrayRef;
+        let b = cast_via_scalars(&array, &DataType::Int8, &DEFAULT_CAST_OPTIONS).unwrap();
+        let c = b.as_any().downcast_ref::<Int8Array>().unwrap();
+        assert_eq!(&Int8Array::from(vec![Some(1), None, None]), c);
+        assert!(cast_via_scalars(&array, &DataType::Int8, &options).is_err());
+    }
+
+    #[test]
+    fn test_cast_null_to_numeric_uses_fallback() {
+        let array = Arc::new(NullArray::new(3)) as ArrayRef;
+        assert!(can_cast_types(&DataType::Null, &DataType::Int32));
+        let b = cast(&array, &DataType::Int32).unwrap();
+        assert_eq!(&DataType::Int32, b.data_type());
+        assert_eq!(3, b.len());
+        assert_eq!(3, b.null_count());
+
+        assert!(!can_cast_types(
+            &DataType::Null,
+            &DataType::Date32(DateUnit::Day)
+        ));
+        assert!(cast(&array, &DataType::Date32(DateUnit::Day)).is_err());
+    }
+
+    #[test]
+    fn test_cast_date32_to_date64_keeps_nulls() {
+        let array =
+            Arc::new(Date32Array::from(vec![Some(1), None, Some(-1)])) as ArrayRef;
+        let b = cast(&array, &DataType::Date64(DateUnit::Millisecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(
+            &Date64Array::from(vec![Some(86_400_000), None, Some(-86_400_000)]),
+            c
+        );
+    }
+
+    #[test]
+    fn test_cast_date32_to_timestamp() {
+        let array =
+            Arc::new(Date32Array::from(vec![Some(1), None, Some(17890)])) as ArrayRef;
+
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Millisecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(86_400_000, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(1545696000000, c.value(2));
+
+        let tz = Some(Arc::new("UTC".to_string()));
+        let to_type = DataType::Timestamp(TimeUnit::Second, tz);
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(&to_type, b.data_type());
+        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+        assert_eq!(86_400, c.value(0));
+        assert!(c.is_null(1));
+
+        // 2^30 days do not fit into nanoseconds
+        let array = Arc::new(Date32Array::from(vec![1 << 30])) as ArrayRef;
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let b = cast(&array, &to_type).unwrap();
+        assert!(b.is_null(0));
+        let options = CastOptions { safe: false };
+        assert!(cast_with_options(&array, &to_type, &options).is_err());
+    }
+
     #[test]
     fn test_cast_date64_to_date32() {
         let a = Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
@@ -1511,6 +2418,37 @@ mod tests {
         assert!(c.is_null(2));
     }
 
+    #[test]
+    fn test_cast_timestamp_ms_to_us_and_back() {
+        let tz = Some(Arc::new("+00:00".to_string()));
+        let a = TimestampMillisecondArray::from_opt_vec(
+            vec![Some(1000), None, Some(-1001)],
+            tz.clone(),
+        );
+        let array = Arc::new(a) as ArrayRef;
+
+        let to_type = DataType::Timestamp(TimeUnit::Microsecond, tz);
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(&to_type, b.data_type());
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert_eq!(1_000_000, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(-1_001_000, c.value(2));
+
+        let b = cast(&b, array.data_type()).unwrap();
+        assert_eq!(array.data_type(), b.data_type());
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(1000, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(-1001, c.value(2));
+    }
+
     #[test]
     fn test_cast_from_f64() {
         let f64_values: Vec<f64> = vec![
@@ -2607,6 +3545,47 @@ mod tests {
         assert_eq!(cast_array.data_type(), &Int64);
     }
 
+    #[test]
+    fn test_cast_dict_value_type_keeps_keys() {
+        use DataType::*;
+
+        let keys_builder = PrimitiveBuilder::<Int32Type>::new(10);
+        let values_builder = PrimitiveBuilder::<Int32Type>::new(10);
+        let mut builder = PrimitiveDictionaryBuilder::new(keys_builder, values_builder);
+        builder.append(7).unwrap();
+        builder.append_null().unwrap();
+        builder.append(i32::MAX).unwrap();
+        builder.append(7).unwrap();
+        let array: ArrayRef = Arc::new(builder.finish());
+
+        let cast_type = Dictionary(Box::new(Int32), Box::new(Int64));
+        let cast_array = cast(&array, &cast_type).expect("cast succeeded");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(
+            array_to_strings(&cast_array),
+            vec!["7", "null", "2147483647", "7"]
+        );
+
+        // the keys are not re-encoded
+        assert_eq!(
+            array.data().buffers()[0].data(),
+            cast_array.data().buffers()[0].data()
+        );
+        assert_eq!(
+            array.data().buffers()[0].raw_data(),
+            cast_array.data().buffers()[0].raw_data()
+        );
+
+        // the values are widened
+        let dict_array = cast_array
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+        let values = dict_array.values();
+        let values = values.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(&[7, i32::MAX as i64], values.value_slice(0, 2));
+    }
+
     #[test]
     fn test_cast_primitive_array_to_dict() {
         use DataType::*;
@@ -2651,6 +3630,52 @@ mod tests {
         assert_eq!(array_to_strings(&cast_array), expected);
     }
 
+    #[test]
+    fn test_cast_dict_utf8_round_trip() {
+        use DataType::*;
+
+        // a dictionary with nulls in both the keys and the values
+        let values = StringArray::from(vec![Some("a"), None, Some("b")]);
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(2), Some(0)]);
+        let dict_type = Dictionary(Box::new(Int32), Box::new(Utf8));
+        let data = ArrayData::builder(dict_type.clone())
+            .len(keys.len())
+            .null_count(keys.null_count())
+            .null_bit_buffer(keys.data_ref().null_buffer().unwrap().clone())
+            .add_buffer(keys.data_ref().buffers()[0].clone())
+            .add_child_data(values.data())
+            .build();
+        let array: ArrayRef = Arc::new(DictionaryArray::<Int32Type>::from(data));
+
+        let expected = vec!["a", "null", "null", "b", "a"];
+
+        // materialize the dictionary
+        let flat = cast(&array, &Utf8).expect("cast to UTF-8 succeeded");
+        assert_eq!(&Utf8, flat.data_type());
+        assert_eq!(2, flat.null_count());
+        assert_eq!(expected, array_to_strings(&flat));
+
+        // and encode it again
+        let encoded = cast(&flat, &dict_type).expect("cast to dictionary succeeded");
+        assert_eq!(&dict_type, encoded.data_type());
+        assert_eq!(2, encoded.null_count());
+        assert_eq!(expected, array_to_strings(&encoded));
+
+        let encoded = encoded
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+        let encoded_values = encoded.values();
+        let encoded_values = encoded_values
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        // repeated values are only stored once
+        assert_eq!(2, encoded_values.len());
+        assert_eq!("a", encoded_values.value(0));
+        assert_eq!("b", encoded_values.value(1));
+    }
+
     /// Print the `DictionaryArray` `array` as a vector of strings
     fn array_to_strings(array: &ArrayRef) -> Vec<String> {
         (0..array.len())
@@ -2664,6 +3689,44 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn test_cast_list_to_fixed_size_list() {
+        // [[0, 1, 2], null, [3, 4, 5]], where the null slot is empty
+        let value_data = ArrayData::builder(DataType::Int32)
+            .len(6)
+            .add_buffer(Buffer::from(&[0, 1, 2, 3, 4, 5].to_byte_slice()))
+            .build();
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Int32)))
+            .len(3)
+            .add_buffer(Buffer::from(&[0, 3, 3, 6].to_byte_slice()))
+            .null_bit_buffer(Buffer::from([0b00000101]))
+            .add_child_data(value_data)
+            .build();
+        let array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+
+        let to_type = DataType::FixedSizeList(Box::new(DataType::Int64), 3);
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(&to_type, b.data_type());
+        assert!(b.data_ref().buffers().is_empty());
+        let c = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert_eq!(3, c.len());
+        assert_eq!(1, c.null_count());
+        assert!(c.is_null(1));
+        let last = c.value(2);
+        let last = last.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(&[3, 4, 5], last.value_slice(0, 3));
+
+        // the last list is too short
+        let array = Arc::new(make_list_array()) as ArrayRef;
+        let to_type = DataType::FixedSizeList(Box::new(DataType::Int32), 3);
+        assert!(cast(&array, &to_type).is_err());
+        let options = CastOptions { safe: false };
+        assert!(cast_with_options(&array, &to_type, &options).is_err());
+
+        let to_type = DataType::FixedSizeList(Box::new(DataType::Int32), -1);
+        assert!(cast(&array, &to_type).is_err());
+    }
+
     #[test]
     fn test_can_cast_types() {
         // this function attempts to ensure that can_cast_types stays
@@ -2684,14 +3747,14 @@ mod tests {
                     (Ok(_), false) => {
                         panic!("Was able to cast array from {:?} to {:?} but can_cast_types reported false",
                                array.data_type(), to_type)
-                    },
+                    }
                     (Err(e), true) => {
                         panic!("Was not able to cast array from {:?} to {:?} but can_cast_types reported true. \
                                 Error was {:?}",
                                array.data_type(), to_type, e)
-                    },
+                    }
                     // otherwise it was a match
-                    _=> {},
+                    _ => {}
                 };
             }
         }
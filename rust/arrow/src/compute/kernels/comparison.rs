@@ -155,6 +155,52 @@ pub fn like_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray
     Ok(PrimitiveArray::<BooleanType>::from(Arc::new(data)))
 }
 
+/// Perform SQL `left LIKE pattern` operation on a `StringArray` and a single pattern.
+///
+/// `%` matches any number of characters and `_` matches exactly one character, while
+/// any other character matches itself. The whole value must match the pattern. Null
+/// values return null.
+///
+/// The pattern is compiled to a regular expression once for the whole array.
+pub fn like(array: &StringArray, pattern: &str) -> Result<BooleanArray> {
+    let mut re_pattern = String::from("^(?s:");
+    for c in pattern.chars() {
+        match c {
+            '%' => re_pattern.push_str(".*"),
+            '_' => re_pattern.push('.'),
+            c => re_pattern.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    re_pattern.push_str(")$");
+    let re = Regex::new(&re_pattern).map_err(|e| {
+        ArrowError::ComputeError(format!(
+            "Unable to build regex from LIKE pattern: {}",
+            e
+        ))
+    })?;
+
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let mut result = BooleanBufferBuilder::new(array.len());
+    for i in 0..array.len() {
+        result.append(re.is_match(array.value(i)))?;
+    }
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        array.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![result.finish()],
+        vec![],
+    );
+    Ok(PrimitiveArray::<BooleanType>::from(Arc::new(data)))
+}
+
 pub fn nlike_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
     let mut map = HashMap::new();
     if left.len() != right.len() {
@@ -1097,6 +1143,37 @@ mod tests {
         eq_utf8,
         vec![true, false, false, false]
     );
+    test_utf8_scalar!(
+        test_utf8_array_like_scalar,
+        vec!["abc", "axc", "abd"],
+        "a_c",
+        like,
+        vec![true, true, false]
+    );
+    test_utf8_scalar!(
+        test_utf8_array_like_scalar_percent,
+        vec!["arrow", "parrot", "ar", "a.row", "a\nrow"],
+        "a%row",
+        like,
+        vec![true, false, false, true, true]
+    );
+    test_utf8_scalar!(
+        test_utf8_array_like_scalar_regex_chars,
+        vec!["a.c", "abc", "1+1", "11"],
+        "a.c",
+        like,
+        vec![true, false, false, false]
+    );
+
+    #[test]
+    fn test_utf8_array_like_scalar_nulls() {
+        let array = StringArray::from(vec![Some("x"), Some("abc"), None, Some("axc")]);
+        let array = array.slice(1, 3);
+        let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+        let res = like(&array, "a_c").unwrap();
+        assert_eq!(BooleanArray::from(vec![Some(true), None, Some(true)]), res);
+    }
+
     test_utf8_scalar!(
         test_utf8_array_eq_scalar,
         vec!["arrow", "parquet", "datafusion", "flight"],
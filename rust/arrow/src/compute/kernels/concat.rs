@@ -31,9 +31,12 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
+use std::sync::Arc;
+
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
 
 use TimeUnit::*;
 
@@ -41,22 +44,39 @@ use TimeUnit::*;
 ///
 /// Returns a new ArrayRef.
 pub fn concat(array_list: &[ArrayRef]) -> Result<ArrayRef> {
+    concat_dyn(&array_list.iter().collect::<Vec<&ArrayRef>>())
+}
+
+/// Concatenate multiple borrowed `ArrayRef` with the same type, dispatching on their
+/// data type to the typed concatenation.
+///
+/// This is like [`concat`] for callers that only hold references to the arrays, such as
+/// the columns of several record batches. Returns an error if the arrays have different
+/// data types.
+pub fn concat_dyn(array_list: &[&ArrayRef]) -> Result<ArrayRef> {
     if array_list.is_empty() {
         return Err(ArrowError::ComputeError(
             "concat requires input of at least one array".to_string(),
         ));
     }
+    let data_type = array_list[0].data_type();
+    if let Some(other) = array_list.iter().find(|a| a.data_type() != data_type) {
+        return Err(ArrowError::ComputeError(format!(
+            "concat requires input arrays of the same type, found {:?} and {:?}",
+            data_type,
+            other.data_type()
+        )));
+    }
     let array_data_list = &array_list
         .iter()
         .map(|a| a.data_ref().clone())
         .collect::<Vec<ArrayDataRef>>();
 
     match array_data_list[0].data_type() {
-        DataType::Utf8 => {
-            let mut builder = StringBuilder::new(0);
-            builder.append_data(array_data_list)?;
-            Ok(ArrayBuilder::finish(&mut builder))
-        }
+        DataType::Utf8 => concat_with(StringBuilder::new(0), array_data_list),
+        DataType::LargeUtf8 => concat_with(LargeStringBuilder::new(0), array_data_list),
+        DataType::Binary => concat_with(BinaryBuilder::new(0), array_data_list),
+        DataType::LargeBinary => concat_with(LargeBinaryBuilder::new(0), array_data_list),
         DataType::Boolean => {
             let mut builder = PrimitiveArray::<BooleanType>::builder(0);
             builder.append_data(array_data_list)?;
@@ -115,6 +135,20 @@ pub fn concat(array_list: &[ArrayRef]) -> Result<ArrayRef> {
             concat_primitive::<DurationNanosecondType>(array_data_list)
         }
         DataType::List(nested_type) => concat_list(array_data_list, *nested_type.clone()),
+        DataType::Dictionary(key_type, value_type) => match key_type.as_ref() {
+            DataType::Int8 => concat_dictionary::<Int8Type>(array_list, value_type),
+            DataType::Int16 => concat_dictionary::<Int16Type>(array_list, value_type),
+            DataType::Int32 => concat_dictionary::<Int32Type>(array_list, value_type),
+            DataType::Int64 => concat_dictionary::<Int64Type>(array_list, value_type),
+            DataType::UInt8 => concat_dictionary::<UInt8Type>(array_list, value_type),
+            DataType::UInt16 => concat_dictionary::<UInt16Type>(array_list, value_type),
+            DataType::UInt32 => concat_dictionary::<UInt32Type>(array_list, value_type),
+            DataType::UInt64 => concat_dictionary::<UInt64Type>(array_list, value_type),
+            t => Err(ArrowError::ComputeError(format!(
+                "Concat not supported for dictionary with key type {:?}",
+                t
+            ))),
+        },
         t => Err(ArrowError::ComputeError(format!(
             "Concat not supported for data type {:?}",
             t
@@ -122,6 +156,19 @@ pub fn concat(array_list: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Concatenate arrays by appending their data to `builder`
+///
+/// For variable-width arrays, the builder shifts the offsets of each array by the
+/// length of the values appended before it.
+#[inline]
+fn concat_with<B: ArrayBuilder>(
+    mut builder: B,
+    array_data_list: &[ArrayDataRef],
+) -> Result<ArrayRef> {
+    builder.append_data(array_data_list)?;
+    Ok(ArrayBuilder::finish(&mut builder))
+}
+
 #[inline]
 fn concat_primitive<T>(array_data_list: &[ArrayDataRef]) -> Result<ArrayRef>
 where
@@ -163,10 +210,154 @@ fn concat_list(
     }
 }
 
+/// Concatenate dictionary arrays with keys of type `K` into a single dictionary array.
+///
+/// The values of all dictionaries are merged into one dictionary, in the order they are
+/// first referenced, and the keys are remapped to it.
+fn concat_dictionary<K>(
+    array_list: &[&ArrayRef],
+    value_type: &DataType,
+) -> Result<ArrayRef>
+where
+    K: ArrowDictionaryKeyType,
+{
+    let dicts = array_list
+        .iter()
+        .map(|a| a.as_any().downcast_ref::<DictionaryArray<K>>().unwrap())
+        .collect::<Vec<_>>();
+    match value_type {
+        DataType::Utf8 => concat_string_dictionary(&dicts),
+        DataType::Int8 => concat_primitive_dictionary::<K, Int8Type>(&dicts),
+        DataType::Int16 => concat_primitive_dictionary::<K, Int16Type>(&dicts),
+        DataType::Int32 => concat_primitive_dictionary::<K, Int32Type>(&dicts),
+        DataType::Int64 => concat_primitive_dictionary::<K, Int64Type>(&dicts),
+        DataType::UInt8 => concat_primitive_dictionary::<K, UInt8Type>(&dicts),
+        DataType::UInt16 => concat_primitive_dictionary::<K, UInt16Type>(&dicts),
+        DataType::UInt32 => concat_primitive_dictionary::<K, UInt32Type>(&dicts),
+        DataType::UInt64 => concat_primitive_dictionary::<K, UInt64Type>(&dicts),
+        t => Err(ArrowError::ComputeError(format!(
+            "Concat not supported for dictionary with value type {:?}",
+            t
+        ))),
+    }
+}
+
+fn concat_string_dictionary<K>(dicts: &[&DictionaryArray<K>]) -> Result<ArrayRef>
+where
+    K: ArrowDictionaryKeyType,
+{
+    let len = dicts.iter().map(|d| d.len()).sum();
+    let mut builder = StringDictionaryBuilder::new(
+        PrimitiveBuilder::<K>::new(len),
+        StringBuilder::new(0),
+    );
+    for dict in dicts {
+        let values = dict.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        let keys = dict.keys_array();
+        for i in 0..keys.len() {
+            let key = if keys.is_valid(i) {
+                keys.value(i).to_usize()
+            } else {
+                None
+            };
+            match key {
+                Some(key) if values.is_valid(key) => {
+                    builder.append(values.value(key))?;
+                }
+                _ => builder.append_null()?,
+            }
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+fn concat_primitive_dictionary<K, V>(dicts: &[&DictionaryArray<K>]) -> Result<ArrayRef>
+where
+    K: ArrowDictionaryKeyType,
+    V: ArrowNumericType,
+{
+    let len = dicts.iter().map(|d| d.len()).sum();
+    let mut builder = PrimitiveDictionaryBuilder::new(
+        PrimitiveBuilder::<K>::new(len),
+        PrimitiveBuilder::<V>::new(0),
+    );
+    for dict in dicts {
+        let values = dict.values();
+        let values = values.as_any().downcast_ref::<PrimitiveArray<V>>().unwrap();
+        let keys = dict.keys_array();
+        for i in 0..keys.len() {
+            let key = if keys.is_valid(i) {
+                keys.value(i).to_usize()
+            } else {
+                None
+            };
+            match key {
+                Some(key) if values.is_valid(key) => {
+                    builder.append(values.value(key))?;
+                }
+                _ => builder.append_null()?,
+            }
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Concatenates consecutive `batches` into batches of at least `target_rows` rows each,
+/// except for the last batch which may be smaller.
+///
+/// Batches that already have at least `target_rows` rows are returned as they are.
+/// Returns an error if the schema of any batch differs from `schema`.
+pub fn coalesce_batches(
+    schema: &Arc<Schema>,
+    batches: Vec<RecordBatch>,
+    target_rows: usize,
+) -> Result<Vec<RecordBatch>> {
+    let mut coalesced = vec![];
+    let mut pending: Vec<RecordBatch> = vec![];
+    let mut pending_rows = 0;
+    for batch in batches {
+        if batch.schema() != *schema {
+            return Err(ArrowError::InvalidArgumentError(
+                "coalesce_batches requires all batches to have the given schema"
+                    .to_string(),
+            ));
+        }
+        pending_rows += batch.num_rows();
+        pending.push(batch);
+        if pending_rows >= target_rows {
+            coalesced.push(concat_batches(schema, &pending)?);
+            pending.clear();
+            pending_rows = 0;
+        }
+    }
+    if !pending.is_empty() {
+        coalesced.push(concat_batches(schema, &pending)?);
+    }
+    Ok(coalesced)
+}
+
+/// Concatenates the columns of `batches` into a single batch.
+fn concat_batches(schema: &Arc<Schema>, batches: &[RecordBatch]) -> Result<RecordBatch> {
+    if batches.len() == 1 {
+        return Ok(batches[0].clone());
+    }
+    let columns = (0..schema.fields().len())
+        .map(|i| {
+            concat_dyn(
+                &batches
+                    .iter()
+                    .map(|batch| batch.column(i))
+                    .collect::<Vec<&ArrayRef>>(),
+            )
+        })
+        .collect::<Result<Vec<ArrayRef>>>()?;
+    RecordBatch::try_new(schema.clone(), columns)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Arc;
 
     #[test]
     fn test_concat_empty_vec() -> Result<()> {
@@ -193,6 +384,105 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_concat_mismatched_types_is_compute_error() {
+        let re = concat(&[
+            Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            Arc::new(Int64Array::from(vec![3])) as ArrayRef,
+        ]);
+        match re {
+            Err(ArrowError::ComputeError(msg)) => assert_eq!(
+                "concat requires input arrays of the same type, found Int32 and Int64",
+                msg
+            ),
+            other => panic!("expected ComputeError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_concat_string_dictionaries() -> Result<()> {
+        let arr = concat(&[
+            Arc::new(
+                vec!["a", "b"]
+                    .into_iter()
+                    .collect::<DictionaryArray<Int32Type>>(),
+            ) as ArrayRef,
+            Arc::new(
+                vec!["b", "c"]
+                    .into_iter()
+                    .collect::<DictionaryArray<Int32Type>>(),
+            ) as ArrayRef,
+        ])?;
+        assert_eq!(
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            arr.data_type()
+        );
+
+        let dict = arr
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+        let values = dict.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(StringArray::from(vec!["a", "b", "c"]), *values);
+        assert_eq!(Int32Array::from(vec![0, 1, 1, 2]), dict.keys_array());
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_primitive_dictionaries() -> Result<()> {
+        let mut builder = PrimitiveDictionaryBuilder::new(
+            PrimitiveBuilder::<UInt8Type>::new(3),
+            PrimitiveBuilder::<Int64Type>::new(2),
+        );
+        builder.append(10)?;
+        builder.append_null()?;
+        builder.append(20)?;
+        let first = builder.finish();
+        builder.append(20)?;
+        builder.append(30)?;
+        let second = builder.finish();
+
+        let arr = concat(&[Arc::new(first) as ArrayRef, Arc::new(second) as ArrayRef])?;
+        let dict = arr
+            .as_any()
+            .downcast_ref::<DictionaryArray<UInt8Type>>()
+            .unwrap();
+        let values = dict.values();
+        let values = values.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(Int64Array::from(vec![10, 20, 30]), *values);
+        assert_eq!(
+            UInt8Array::from(vec![Some(0), None, Some(1), Some(1), Some(2)]),
+            dict.keys_array()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_sliced_dictionaries() -> Result<()> {
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("b"), Some("c")]
+                .into_iter()
+                .collect();
+        let sliced = dict.slice(1, 2);
+        let other: DictionaryArray<Int32Type> = vec!["c"].into_iter().collect();
+
+        let arr = concat(&[sliced, Arc::new(other) as ArrayRef])?;
+        assert_eq!(3, arr.len());
+        let dict = arr
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+        let values = dict.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(StringArray::from(vec!["b", "c"]), *values);
+        assert_eq!(
+            Int32Array::from(vec![None, Some(0), Some(1)]),
+            dict.keys_array()
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_concat_string_arrays() -> Result<()> {
         let arr = concat(&[
@@ -228,6 +518,74 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_concat_string_arrays_offsets() -> Result<()> {
+        let arr = concat(&[
+            Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef,
+            Arc::new(StringArray::from(vec![Some(""), None, Some("c")])) as ArrayRef,
+        ])?;
+        let arr = arr.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(5, arr.len());
+        assert_eq!(1, arr.null_count());
+        assert!(arr.is_null(3));
+        assert_eq!(
+            vec![0, 1, 2, 2, 2, 3],
+            (0..=arr.len())
+                .map(|i| arr.value_offset(i))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!("a", arr.value(0));
+        assert_eq!("b", arr.value(1));
+        assert_eq!("", arr.value(2));
+        assert_eq!("c", arr.value(4));
+
+        let arr = concat(&[
+            Arc::new(LargeStringArray::from(vec!["a", "b"])) as ArrayRef,
+            Arc::new(LargeStringArray::from(vec!["", "c"])) as ArrayRef,
+        ])?;
+        let arr = arr.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(
+            vec![0, 1, 2, 2, 3],
+            (0..=arr.len())
+                .map(|i| arr.value_offset(i))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_binary_arrays() -> Result<()> {
+        let values: Vec<&[u8]> = vec![b"one", b"two", b"three"];
+        let arr = concat(&[
+            Arc::new(BinaryArray::from(values.clone())).slice(1, 2),
+            Arc::new(BinaryArray::from(vec![None, Some(&b"four"[..])])) as ArrayRef,
+        ])?;
+        assert_eq!(&DataType::Binary, arr.data_type());
+        let arr = arr.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(4, arr.len());
+        assert_eq!(1, arr.null_count());
+        assert_eq!(
+            vec![0, 3, 8, 8, 12],
+            (0..=arr.len())
+                .map(|i| arr.value_offset(i))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(b"two", arr.value(0));
+        assert_eq!(b"three", arr.value(1));
+        assert!(arr.is_null(2));
+        assert_eq!(b"four", arr.value(3));
+
+        let arr = concat(&[
+            Arc::new(LargeBinaryArray::from(values.clone())) as ArrayRef,
+            Arc::new(LargeBinaryArray::from(values)) as ArrayRef,
+        ])?;
+        let arr = arr.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+        assert_eq!(6, arr.len());
+        assert_eq!(b"three", arr.value(5));
+        assert_eq!(22, arr.value_offset(6));
+        Ok(())
+    }
+
     #[test]
     fn test_concat_primitive_arrays() -> Result<()> {
         let arr = concat(&[
@@ -388,4 +746,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_concat_dyn() -> Result<()> {
+        let a = Arc::new(StringArray::from(vec![Some("a"), None])) as ArrayRef;
+        let b = Arc::new(StringArray::from(vec!["b", "c"])) as ArrayRef;
+        let arr = concat_dyn(&[&a, &b])?;
+        let expected = StringArray::from(vec![Some("a"), None, Some("b"), Some("c")]);
+        assert_eq!(
+            &expected,
+            arr.as_any().downcast_ref::<StringArray>().unwrap()
+        );
+
+        let c = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let d = Arc::new(Int32Array::from(vec![Some(3), None])) as ArrayRef;
+        let arr = concat_dyn(&[&c, &d])?;
+        let expected = Int32Array::from(vec![Some(1), Some(2), Some(3), None]);
+        assert_eq!(
+            &expected,
+            arr.as_any().downcast_ref::<Int32Array>().unwrap()
+        );
+
+        match concat_dyn(&[&a, &c]) {
+            Err(ArrowError::ComputeError(_)) => {}
+            other => panic!("expected a compute error, got {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_batches() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let batches = (0..5)
+            .map(|i| {
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(Int32Array::from(vec![Some(i)])),
+                        Arc::new(StringArray::from(vec![i.to_string().as_str()])),
+                    ],
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let coalesced = coalesce_batches(&schema, batches, 2)?;
+        let sizes: Vec<usize> = coalesced.iter().map(|b| b.num_rows()).collect();
+        assert_eq!(vec![2, 2, 1], sizes);
+
+        let a = coalesced[1]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&Int32Array::from(vec![2, 3]), a);
+        let b = coalesced[1]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(&StringArray::from(vec!["2", "3"]), b);
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_batches_schema_mismatch() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let other = Arc::new(Schema::new(vec![Field::new("b", DataType::Int32, false)]));
+        let batch =
+            RecordBatch::try_new(other, vec![Arc::new(Int32Array::from(vec![1]))])?;
+        assert!(coalesce_batches(&schema, vec![batch], 2).is_err());
+        Ok(())
+    }
 }
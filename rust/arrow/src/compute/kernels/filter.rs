@@ -789,6 +789,18 @@ where
     FilterContext::new(filter_array)?.filter_dictionary_array(data_array)
 }
 
+/// Returns the indices of the slots of the filter that are valid and `true`.
+///
+/// The indices can be passed to [`take_dyn`](crate::compute::kernels::take::take_dyn)
+/// to apply the same filter to several arrays.
+pub fn filter_to_indices(filter: &BooleanArray) -> Int32Array {
+    let indices: Vec<i32> = (0..filter.len())
+        .filter(|&i| filter.is_valid(i) && filter.value(i))
+        .map(|i| i as i32)
+        .collect();
+    Int32Array::from(indices)
+}
+
 /// Returns a new RecordBatch with arrays containing only values matching the filter.
 /// The same FilterContext is re-used when filtering arrays in the RecordBatch for better performance.
 pub fn filter_record_batch(
@@ -810,6 +822,18 @@ mod tests {
     use crate::buffer::Buffer;
     use crate::datatypes::ToByteSlice;
 
+    #[test]
+    fn test_filter_to_indices() {
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        let indices = filter_to_indices(&mask);
+        assert_eq!(Int32Array::from(vec![0, 3]), indices);
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![5, 6, 7, 8]));
+        let taken = crate::compute::take_dyn(&values, &indices).unwrap();
+        let taken = taken.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&Int32Array::from(vec![5, 8]), taken);
+    }
+
     macro_rules! def_temporal_test {
         ($test:ident, $array_type: ident, $data: expr) => {
             #[test]
@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines join kernels for `PrimitiveArray`s
+
+use crate::array::*;
+use crate::datatypes::ArrowNumericType;
+use std::cmp::Ordering;
+
+/// Returns the indices of the matching rows of an inner join between `left` and
+/// `right`, which must both be sorted in ascending order.
+///
+/// The result is a pair of arrays of equal length, where the `i`th slot of each
+/// holds the index into `left` and `right` of the `i`th matching pair. Keys that
+/// appear several times on both sides produce every combination of their indices.
+/// Null slots do not match anything.
+///
+/// For example, joining `[1, 2, 3]` with `[2, 3, 4]` returns `([1, 2], [0, 1])`.
+pub fn merge_join_indices<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> (Int32Array, Int32Array)
+where
+    T: ArrowNumericType,
+{
+    let mut left_indices = Int32Builder::new(left.len().min(right.len()));
+    let mut right_indices = Int32Builder::new(left.len().min(right.len()));
+
+    let (mut l, mut r) = (0, 0);
+    while l < left.len() && r < right.len() {
+        if left.is_null(l) {
+            l += 1;
+            continue;
+        }
+        if right.is_null(r) {
+            r += 1;
+            continue;
+        }
+        let key = left.value(l);
+        match key.partial_cmp(&right.value(r)) {
+            Some(Ordering::Less) => l += 1,
+            Some(Ordering::Greater) => r += 1,
+            Some(Ordering::Equal) => {
+                // find the end of the run of equal keys on each side
+                let l_end = run_end(left, l, key);
+                let r_end = run_end(right, r, key);
+                for i in l..l_end {
+                    for j in r..r_end {
+                        left_indices.append_value(i as i32).unwrap();
+                        right_indices.append_value(j as i32).unwrap();
+                    }
+                }
+                l = l_end;
+                r = r_end;
+            }
+            // NaN does not match anything, skip it on the side it is on only so the
+            // other key can still match later slots. NaN is the only key that cannot
+            // be compared with itself.
+            None => {
+                if key.partial_cmp(&key).is_none() {
+                    l += 1;
+                } else {
+                    r += 1;
+                }
+            }
+        }
+    }
+
+    (left_indices.finish(), right_indices.finish())
+}
+
+/// Returns the index after the last consecutive non-null slot from `start` equal
+/// to `key`
+fn run_end<T>(array: &PrimitiveArray<T>, start: usize, key: T::Native) -> usize
+where
+    T: ArrowNumericType,
+{
+    let mut end = start;
+    while end < array.len() && array.is_valid(end) && array.value(end) == key {
+        end += 1;
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_join_indices() {
+        let left = Int32Array::from(vec![1, 2, 3]);
+        let right = Int32Array::from(vec![2, 3, 4]);
+        let (l, r) = merge_join_indices(&left, &right);
+        assert_eq!(&[1, 2], l.value_slice(0, l.len()));
+        assert_eq!(&[0, 1], r.value_slice(0, r.len()));
+        assert_eq!(0, l.null_count());
+        assert_eq!(0, r.null_count());
+    }
+
+    #[test]
+    fn test_merge_join_indices_duplicates() {
+        let left = Int64Array::from(vec![1, 2, 2, 5]);
+        let right = Int64Array::from(vec![2, 2, 5, 5, 6]);
+        let (l, r) = merge_join_indices(&left, &right);
+        assert_eq!(&[1, 1, 2, 2, 3, 3], l.value_slice(0, l.len()));
+        assert_eq!(&[0, 1, 0, 1, 2, 3], r.value_slice(0, r.len()));
+    }
+
+    #[test]
+    fn test_merge_join_indices_nulls() {
+        let left = Int32Array::from(vec![None, None, Some(1), Some(3)]);
+        let right = Int32Array::from(vec![None, Some(1), Some(2), Some(3), None]);
+        let (l, r) = merge_join_indices(&left, &right);
+        assert_eq!(&[2, 3], l.value_slice(0, l.len()));
+        assert_eq!(&[1, 3], r.value_slice(0, r.len()));
+
+        let empty = Int32Array::from(Vec::<i32>::new());
+        let (l, r) = merge_join_indices(&left, &empty);
+        assert_eq!(0, l.len());
+        assert_eq!(0, r.len());
+    }
+
+    #[test]
+    fn test_merge_join_indices_nan() {
+        let left = Float64Array::from(vec![std::f64::NAN, 1.0, 2.0]);
+        let right = Float64Array::from(vec![1.0, 2.0]);
+        let (l, r) = merge_join_indices(&left, &right);
+        assert_eq!(&[1, 2], l.value_slice(0, l.len()));
+        assert_eq!(&[0, 1], r.value_slice(0, r.len()));
+
+        let (l, r) = merge_join_indices(&right, &left);
+        assert_eq!(&[0, 1], l.value_slice(0, l.len()));
+        assert_eq!(&[1, 2], r.value_slice(0, r.len()));
+    }
+}
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines kernel for length of a string array
+//! Defines kernel for length of string, binary and list arrays
 
 use crate::datatypes::ToByteSlice;
 use crate::{array::*, buffer::Buffer};
@@ -25,7 +25,15 @@ use crate::{
 };
 use std::sync::Arc;
 
-fn length_string<OffsetSize>(array: &Array, data_type: DataType) -> Result<ArrayRef>
+/// Computes the lengths of the slots of an array whose first buffer holds the offsets,
+/// such as string, binary and list arrays. Each length is multiplied by `multiplier`.
+///
+/// Returns an error if a multiplied length does not fit in `OffsetSize`.
+fn length_from_offsets<OffsetSize>(
+    array: &Array,
+    data_type: DataType,
+    multiplier: usize,
+) -> Result<ArrayRef>
 where
     OffsetSize: OffsetSizeTrait,
 {
@@ -33,19 +41,65 @@ where
     let offsets = array.data_ref().clone().buffers()[0].clone();
     // this is a 30% improvement over iterating over u8s and building OffsetSize, which
     // justifies the usage of `unsafe`.
-    let slice: &[OffsetSize] =
-        &unsafe { offsets.typed_data::<OffsetSize>() }[array.offset()..];
+    let slice: &[OffsetSize] = &unsafe { offsets.typed_data::<OffsetSize>() }
+        [array.offset()..array.offset() + array.len() + 1];
+
+    let lengths = slice.windows(2).map(|offset| offset[1] - offset[0]);
+    let lengths: Vec<OffsetSize> = if multiplier == 1 {
+        lengths.collect()
+    } else {
+        lengths
+            .map(|length| {
+                length
+                    .to_usize()
+                    .and_then(|length| length.checked_mul(multiplier))
+                    .and_then(OffsetSize::from_usize)
+                    .ok_or_else(|| {
+                        ArrowError::ComputeError(format!(
+                            "Length {:?} overflows {:?} when multiplied by {}",
+                            length, data_type, multiplier
+                        ))
+                    })
+            })
+            .collect::<Result<_>>()?
+    };
+
+    Ok(lengths_array(array, data_type, lengths))
+}
 
-    let lengths: Vec<OffsetSize> = slice
-        .windows(2)
-        .map(|offset| offset[1] - offset[0])
+/// Computes the number of characters of each slot of a \[Large\]StringArray.
+fn char_length_of_strings<OffsetSize>(array: &Array, data_type: DataType) -> ArrayRef
+where
+    OffsetSize: StringOffsetSizeTrait,
+{
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericStringArray<OffsetSize>>()
+        .unwrap();
+    // a string has at most as many characters as bytes, so the counts fit the offsets
+    let lengths: Vec<OffsetSize> = (0..array.len())
+        .map(|i| {
+            if array.is_valid(i) {
+                OffsetSize::from_usize(array.value(i).chars().count()).unwrap()
+            } else {
+                OffsetSize::zero()
+            }
+        })
         .collect();
 
+    lengths_array(array, data_type, lengths)
+}
+
+/// Builds an array of type `data_type` from `lengths`, with the nulls of `array`.
+fn lengths_array<OffsetSize: OffsetSizeTrait>(
+    array: &Array,
+    data_type: DataType,
+    lengths: Vec<OffsetSize>,
+) -> ArrayRef {
     let null_bit_buffer = array
         .data_ref()
-        .null_bitmap()
-        .as_ref()
-        .map(|b| b.bits.clone());
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
 
     let data = ArrayData::new(
         data_type,
@@ -56,18 +110,23 @@ where
         vec![Buffer::from(lengths.to_byte_slice())],
         vec![],
     );
-    Ok(make_array(Arc::new(data)))
+    make_array(Arc::new(data))
 }
 
-/// Returns an array of Int32/Int64 denoting the number of characters in each string in the array.
+/// Returns an array of Int32/Int64 denoting the length of each element in the array.
 ///
-/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8
+/// * this only accepts Utf8/LargeUtf8, Binary/LargeBinary and List/LargeList arrays
 /// * length of null is null.
-/// * length is in number of bytes
+/// * length of strings and binaries is in number of bytes
+/// * length of lists is their number of elements
 pub fn length(array: &Array) -> Result<ArrayRef> {
     match array.data_type() {
-        DataType::Utf8 => length_string::<i32>(array, DataType::Int32),
-        DataType::LargeUtf8 => length_string::<i64>(array, DataType::Int64),
+        DataType::Utf8 | DataType::Binary | DataType::List(_) => {
+            length_from_offsets::<i32>(array, DataType::Int32, 1)
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary | DataType::LargeList(_) => {
+            length_from_offsets::<i64>(array, DataType::Int64, 1)
+        }
         _ => Err(ArrowError::ComputeError(format!(
             "length not supported for {:?}",
             array.data_type()
@@ -75,6 +134,43 @@ pub fn length(array: &Array) -> Result<ArrayRef> {
     }
 }
 
+/// Returns an array of Int32/Int64 denoting the number of characters of each string in
+/// the array, counted as Unicode scalar values.
+///
+/// * this only accepts Utf8/LargeUtf8 arrays
+/// * length of null is null.
+pub fn char_length(array: &Array) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(char_length_of_strings::<i32>(array, DataType::Int32)),
+        DataType::LargeUtf8 => Ok(char_length_of_strings::<i64>(array, DataType::Int64)),
+        _ => Err(ArrowError::ComputeError(format!(
+            "char_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns an array of Int32/Int64 denoting the number of bits of each element in the
+/// array, i.e. its length in bytes multiplied by 8.
+///
+/// * this only accepts Utf8/LargeUtf8 and Binary/LargeBinary arrays
+/// * length of null is null.
+/// * returns an error if a number of bits does not fit in the result type
+pub fn bit_length(array: &Array) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Utf8 | DataType::Binary => {
+            length_from_offsets::<i32>(array, DataType::Int32, 8)
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary => {
+            length_from_offsets::<i64>(array, DataType::Int64, 8)
+        }
+        _ => Err(ArrowError::ComputeError(format!(
+            "bit_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,6 +277,58 @@ mod tests {
             .collect::<Result<()>>()
     }
 
+    #[test]
+    fn test_char_length() -> Result<()> {
+        let array = StringArray::from(vec![Some("é"), Some("ab"), None]);
+
+        let result = char_length(&array)?;
+        let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&Int32Array::from(vec![Some(1), Some(2), None]), result);
+
+        let result = length(&array)?;
+        let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&Int32Array::from(vec![Some(2), Some(2), None]), result);
+
+        let array = LargeStringArray::from(vec![None, Some("💖a"), Some("")]);
+        let array = array.slice(1, 2);
+        let result = char_length(array.as_ref())?;
+        let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(&Int64Array::from(vec![Some(2), Some(0)]), result);
+
+        assert!(char_length(&BinaryArray::from(vec![b"ab".as_ref()])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_length() -> Result<()> {
+        let array = StringArray::from(vec![Some("é"), Some("ab"), None]);
+        let result = bit_length(&array)?;
+        let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&Int32Array::from(vec![Some(16), Some(16), None]), result);
+
+        let array = LargeBinaryArray::from(vec![b"abc".as_ref(), b"".as_ref()]);
+        let result = bit_length(&array)?;
+        let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(&Int64Array::from(vec![24, 0]), result);
+
+        let array = Int32Array::from(vec![1, 2]);
+        assert!(bit_length(&array).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_length_overflow() {
+        // only the offsets are read, so the values need not be allocated
+        let data = ArrayData::builder(DataType::Binary)
+            .len(2)
+            .add_buffer(Buffer::from(&[0, 1, i32::MAX / 4].to_byte_slice()))
+            .add_buffer(Buffer::from(&[0u8; 8][..]))
+            .build();
+        let array = BinaryArray::from(data);
+        assert!(bit_length(&array).is_err());
+        assert!(length(&array).is_ok());
+    }
+
     /// Tests that length is not valid for u64.
     #[test]
     fn wrong_type() -> Result<()> {
@@ -208,4 +356,64 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn binary() -> Result<()> {
+        let array = BinaryArray::from(vec![Some(&b"a"[..]), Some(&b"bbb"[..]), None]);
+        let result = length(&array)?;
+        let expected = Int32Array::from(vec![Some(1), Some(3), None]);
+        assert_eq!(expected.data(), result.data());
+
+        let array = LargeBinaryArray::from(vec![Some(&b""[..]), None, Some(&b"cc"[..])]);
+        let result = length(&array)?;
+        let expected = Int64Array::from(vec![Some(0), None, Some(2)]);
+        assert_eq!(expected.data(), result.data());
+        Ok(())
+    }
+
+    #[test]
+    fn list() -> Result<()> {
+        // [[1, 2], null, [], [3, 4, 5]]
+        let mut builder = ListBuilder::new(Int32Builder::new(5));
+        builder.values().append_slice(&[1, 2])?;
+        builder.append(true)?;
+        builder.append(false)?;
+        builder.append(true)?;
+        builder.values().append_slice(&[3, 4, 5])?;
+        builder.append(true)?;
+        let array = builder.finish();
+
+        let result = length(&array)?;
+        let expected = Int32Array::from(vec![Some(2), None, Some(0), Some(3)]);
+        assert_eq!(expected.data(), result.data());
+
+        let mut builder = LargeListBuilder::new(Int32Builder::new(5));
+        builder.values().append_slice(&[1, 2, 3])?;
+        builder.append(true)?;
+        let array = builder.finish();
+
+        let result = length(&array)?;
+        assert_eq!(&DataType::Int64, result.data_type());
+        let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(1, result.len());
+        assert_eq!(0, result.null_count());
+        assert_eq!(3, result.value(0));
+        Ok(())
+    }
+
+    /// Tests a sliced array with nulls
+    #[test]
+    fn offsets_with_nulls() -> Result<()> {
+        let a = StringArray::from(vec![Some("a"), None, Some("bbb"), None, Some("cc")]);
+        let b = a.slice(1, 3);
+        let result = length(b.as_ref())?;
+
+        assert_eq!(3, result.len());
+        let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(2, result.null_count());
+        assert!(result.is_null(0));
+        assert_eq!(3, result.value(1));
+        assert!(result.is_null(2));
+        Ok(())
+    }
 }
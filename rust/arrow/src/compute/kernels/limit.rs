@@ -17,7 +17,10 @@
 
 //! Defines miscellaneous array kernels.
 
-use crate::array::ArrayRef;
+use crate::array::{
+    Array, ArrayRef, PrimitiveArray, PrimitiveArrayOps, PrimitiveBuilder,
+};
+use crate::datatypes::ArrowPrimitiveType;
 use crate::error::Result;
 
 /// Returns the array, taking only the number of elements specified
@@ -31,6 +34,24 @@ pub fn limit(array: &ArrayRef, num_elements: usize) -> Result<ArrayRef> {
     Ok(array.slice(0, lim))
 }
 
+/// Returns a new array with the elements of `array` in reverse order.
+///
+/// Null slots are preserved at their mirrored positions.
+pub fn reverse<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowPrimitiveType,
+{
+    let mut builder = PrimitiveBuilder::<T>::new(array.len());
+    for i in (0..array.len()).rev() {
+        if array.is_null(i) {
+            builder.append_null()?;
+        } else {
+            builder.append_value(array.value(i))?;
+        }
+    }
+    Ok(builder.finish())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,6 +62,29 @@ mod tests {
 
     use std::sync::Arc;
 
+    #[test]
+    fn test_reverse() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = reverse(&a).unwrap();
+        assert_eq!(3, b.len());
+        assert_eq!(1, b.null_count());
+        assert_eq!(3, b.value(0));
+        assert!(b.is_null(1));
+        assert_eq!(1, b.value(2));
+    }
+
+    #[test]
+    fn test_reverse_sliced_boolean() {
+        let a = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let b = reverse(a).unwrap();
+        assert_eq!(3, b.len());
+        assert_eq!(true, b.value(0));
+        assert!(b.is_null(1));
+        assert_eq!(false, b.value(2));
+    }
+
     #[test]
     fn test_limit_array() {
         let a: ArrayRef = Arc::new(Int32Array::from(vec![5, 6, 7, 8, 9]));
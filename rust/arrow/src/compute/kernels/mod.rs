@@ -24,9 +24,13 @@ pub mod cast;
 pub mod comparison;
 pub mod concat;
 pub mod filter;
+pub mod join;
 pub mod length;
 pub mod limit;
+pub mod nullif;
 pub mod sort;
+pub mod string;
 pub mod substring;
 pub mod take;
 pub mod temporal;
+pub mod zip;
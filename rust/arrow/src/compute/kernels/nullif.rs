@@ -0,0 +1,76 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the nullif kernel for primitive arrays.
+
+use crate::array::{Array, PrimitiveArray, PrimitiveArrayOps, PrimitiveBuilder};
+use crate::datatypes::ArrowNumericType;
+use crate::error::{ArrowError, Result};
+
+/// Returns a new array that is null where `array` equals `cmp`, and `array` otherwise,
+/// as SQL `NULLIF(array, cmp)` does.
+///
+/// Null slots of `array` stay null, and slots where `cmp` is null keep the value of
+/// `array`. Returns an error if the arrays have different lengths.
+pub fn nullif<T>(
+    array: &PrimitiveArray<T>,
+    cmp: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+{
+    if array.len() != cmp.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform nullif on arrays of different length".to_string(),
+        ));
+    }
+
+    let mut builder = PrimitiveBuilder::<T>::new(array.len());
+    for i in 0..array.len() {
+        if array.is_null(i) || (cmp.is_valid(i) && array.value(i) == cmp.value(i)) {
+            builder.append_null()?;
+        } else {
+            builder.append_value(array.value(i))?;
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float64Array, Int32Array};
+
+    #[test]
+    fn test_nullif() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let cmp = Int32Array::from(vec![1, 5, 3]);
+        let b = nullif(&a, &cmp).unwrap();
+        assert_eq!(Int32Array::from(vec![None, Some(2), None]), b);
+
+        let a = Float64Array::from(vec![None, Some(1.0), Some(2.0), None]);
+        let cmp = Float64Array::from(vec![Some(0.0), None, Some(2.5), None]);
+        let b = nullif(&a, &cmp).unwrap();
+        assert_eq!(
+            Float64Array::from(vec![None, Some(1.0), Some(2.0), None]),
+            b
+        );
+
+        let cmp = Float64Array::from(vec![1.0]);
+        assert!(nullif(&a, &cmp).is_err());
+    }
+}
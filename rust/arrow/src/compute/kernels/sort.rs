@@ -465,7 +465,7 @@ pub fn lexsort_to_indices(columns: &[SortColumn]) -> Result<UInt32Array> {
         .iter()
         .map(|column| -> Result<(&Array, Box<OrdArray>, SortOptions)> {
             // row count check
-            let curr_row_count = column.values.len() - column.values.offset();
+            let curr_row_count = column.values.len();
             match row_count {
                 None => {
                     row_count = Some(curr_row_count);
@@ -1036,6 +1036,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sort_to_indices_floats_nan_and_null() {
+        // NaN is greater than any other value and is not affected by `nulls_first`
+        let cases = vec![
+            (false, true, vec![2, 0, 3, 1]),
+            (false, false, vec![0, 3, 1, 2]),
+            (true, true, vec![2, 1, 3, 0]),
+            (true, false, vec![1, 3, 0, 2]),
+        ];
+        for (descending, nulls_first, expected) in cases {
+            let options = Some(SortOptions {
+                descending,
+                nulls_first,
+            });
+            test_sort_to_indices_primitive_arrays::<Float64Type>(
+                vec![Some(1.0), Some(f64::NAN), None, Some(2.0)],
+                options,
+                expected.clone(),
+            );
+            test_sort_to_indices_primitive_arrays::<Float32Type>(
+                vec![Some(1.0), Some(f32::NAN), None, Some(2.0)],
+                options,
+                expected,
+            );
+        }
+    }
+
     #[test]
     fn test_sort_to_indices_strings() {
         test_sort_to_indices_string_arrays(
@@ -1304,6 +1331,59 @@ mod tests {
         test_lex_sort_arrays(input, expected);
     }
 
+    #[test]
+    fn test_lex_sort_to_indices_tie_break() {
+        // sorted by a ascending with nulls first, ties broken by b descending
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(2),
+            None,
+            Some(1),
+            Some(2),
+            None,
+            Some(1),
+        ]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("x"),
+            Some("p"),
+            None,
+            Some("y"),
+            Some("q"),
+            Some("z"),
+        ]));
+        let columns = vec![
+            SortColumn {
+                values: a.clone(),
+                options: Some(SortOptions {
+                    descending: false,
+                    nulls_first: true,
+                }),
+            },
+            SortColumn {
+                values: b.clone(),
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }),
+            },
+        ];
+        let indices = lexsort_to_indices(&columns).unwrap();
+        assert_eq!(UInt32Array::from(vec![4, 1, 5, 2, 3, 0]), indices);
+
+        // sliced columns are sorted over their logical rows
+        let columns = vec![
+            SortColumn {
+                values: a.slice(2, 4),
+                options: None,
+            },
+            SortColumn {
+                values: b.slice(2, 4),
+                options: None,
+            },
+        ];
+        let indices = lexsort_to_indices(&columns).unwrap();
+        assert_eq!(UInt32Array::from(vec![2, 0, 3, 1]), indices);
+    }
+
     #[test]
     fn test_lex_sort_unaligned_rows() {
         let input = vec![
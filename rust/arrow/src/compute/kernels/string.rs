@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels that transform each string of a \[Large\]StringArray, such as
+//! `upper`, `lower` and `trim`.
+
+use std::sync::Arc;
+
+use crate::error::{ArrowError, Result};
+use crate::{array::*, buffer::Buffer, datatypes::ToByteSlice};
+
+/// Helper function to apply `op` to each valid string of `array`, building the values
+/// and offsets of the result as it goes. The null bitmap of `array` is kept.
+fn string_unary_op<OffsetSize, F>(
+    array: &GenericStringArray<OffsetSize>,
+    op: F,
+) -> Result<GenericStringArray<OffsetSize>>
+where
+    OffsetSize: StringOffsetSizeTrait,
+    F: Fn(&str, &mut String),
+{
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let mut new_values = String::with_capacity(array.value_data().len());
+    let mut new_offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
+    new_offsets.push(OffsetSize::zero());
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            op(array.value(i), &mut new_values);
+        }
+        let offset = OffsetSize::from_usize(new_values.len()).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Transformed strings of {} bytes overflow the offsets of {:?}",
+                new_values.len(),
+                <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE
+            ))
+        })?;
+        new_offsets.push(offset);
+    }
+
+    let data = ArrayData::new(
+        <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
+        array.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![
+            Buffer::from(new_offsets.to_byte_slice()),
+            Buffer::from(new_values.as_bytes()),
+        ],
+        vec![],
+    );
+    Ok(GenericStringArray::<OffsetSize>::from(Arc::new(data)))
+}
+
+/// Returns a new array with each string of `array` converted to upper case, as
+/// defined by the Unicode `Uppercase` property. Null values stay null.
+///
+/// Returns an error if the upper case strings no longer fit into the offsets of the
+/// array, as some characters grow when converted.
+pub fn upper<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+) -> Result<GenericStringArray<OffsetSize>> {
+    string_unary_op(array, |value, values| {
+        values.push_str(&value.to_uppercase())
+    })
+}
+
+/// Returns a new array with each string of `array` converted to lower case, as
+/// defined by the Unicode `Lowercase` property. Null values stay null.
+///
+/// Returns an error if the lower case strings no longer fit into the offsets of the
+/// array, as some characters grow when converted.
+pub fn lower<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+) -> Result<GenericStringArray<OffsetSize>> {
+    string_unary_op(array, |value, values| {
+        values.push_str(&value.to_lowercase())
+    })
+}
+
+/// Returns a new array with the leading and trailing whitespace removed from each
+/// string of `array`. Null values stay null.
+pub fn trim<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+) -> Result<GenericStringArray<OffsetSize>> {
+    string_unary_op(array, |value, values| values.push_str(value.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim() -> Result<()> {
+        let array = StringArray::from(vec![Some(" Hi "), Some("abc"), None]);
+        let result = trim(&array)?;
+        assert_eq!(
+            StringArray::from(vec![Some("Hi"), Some("abc"), None]),
+            result
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_upper_lower() -> Result<()> {
+        let array = StringArray::from(vec![Some(" Hi "), Some("abc"), None]);
+        let result = upper(&array)?;
+        assert_eq!(
+            StringArray::from(vec![Some(" HI "), Some("ABC"), None]),
+            result
+        );
+        let result = lower(&array)?;
+        assert_eq!(
+            StringArray::from(vec![Some(" hi "), Some("abc"), None]),
+            result
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_upper_sliced_large() -> Result<()> {
+        let array =
+            LargeStringArray::from(vec![Some("skip"), None, Some("straße"), Some("")]);
+        let array = array.slice(1, 3);
+        let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        let result = upper(array)?;
+        assert_eq!(
+            LargeStringArray::from(vec![None, Some("STRASSE"), Some("")]),
+            result
+        );
+        Ok(())
+    }
+}
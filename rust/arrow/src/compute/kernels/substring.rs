@@ -26,53 +26,46 @@ use std::sync::Arc;
 
 fn generic_substring<OffsetSize: StringOffsetSizeTrait>(
     array: &GenericStringArray<OffsetSize>,
-    start: OffsetSize,
-    length: &Option<OffsetSize>,
+    start: i64,
+    length: &Option<u64>,
 ) -> Result<ArrayRef> {
-    // compute current offsets
-    let offsets = array.data_ref().clone().buffers()[0].clone();
-    let offsets: &[OffsetSize] = unsafe { offsets.typed_data::<OffsetSize>() };
-
-    // compute null bitmap (copy)
-    let null_bit_buffer = array.data_ref().null_buffer().cloned();
-
-    // compute values
-    let values = &array.data_ref().buffers()[1];
-    let data = values.data();
+    // compute null bitmap (copy), taking the offset of the array into account
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
 
     let mut new_values = Vec::new(); // we have no way to estimate how much this will be.
     let mut new_offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
 
     let mut length_so_far = OffsetSize::zero();
     new_offsets.push(length_so_far);
-    (0..array.len()).for_each(|i| {
-        // the length of this entry
-        let lenght_i: OffsetSize = offsets[i + 1] - offsets[i];
-        // compute where we should start slicing this entry
-        let start = offsets[i]
-            + if start >= OffsetSize::zero() {
-                start
-            } else {
-                lenght_i + start
-            };
-
-        let start = start.max(offsets[i]).min(offsets[i + 1]);
-        // compute the lenght of the slice
-        let length: OffsetSize = length
-            .unwrap_or(lenght_i)
-            // .max(0) is not needed as it is guaranteed
-            .min(offsets[i + 1] - start); // so we do not go beyond this entry
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            new_offsets.push(length_so_far);
+            continue;
+        }
+        let value = array.value(i);
+        // offsets are in characters, not bytes, so that we never split a character
+        let num_chars = value.chars().count() as i64;
+        let char_start = if start >= 0 {
+            start.min(num_chars)
+        } else {
+            (num_chars + start).max(0)
+        };
+        let char_length = length
+            .map(|l| (l.min(i64::MAX as u64) as i64).min(num_chars - char_start))
+            .unwrap_or(num_chars - char_start);
 
-        length_so_far = length_so_far + length;
+        let byte_start = char_to_byte_index(value, char_start as usize);
+        let byte_end =
+            byte_start + char_to_byte_index(&value[byte_start..], char_length as usize);
 
+        new_values.extend_from_slice(value[byte_start..byte_end].as_bytes());
+        length_so_far =
+            length_so_far + OffsetSize::from_usize(byte_end - byte_start).unwrap();
         new_offsets.push(length_so_far);
-
-        // we need usize for ranges
-        let start = start.to_usize().unwrap();
-        let length = length.to_usize().unwrap();
-
-        new_values.extend_from_slice(&data[start..start + length]);
-    });
+    }
 
     let data = ArrayData::new(
         <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
@@ -89,7 +82,18 @@ fn generic_substring<OffsetSize: StringOffsetSizeTrait>(
     Ok(make_array(Arc::new(data)))
 }
 
+/// Returns the byte index at which the `n`th character of `value` starts, or the
+/// length of `value` if it has fewer than `n` characters.
+fn char_to_byte_index(value: &str, n: usize) -> usize {
+    value
+        .char_indices()
+        .nth(n)
+        .map(|(index, _)| index)
+        .unwrap_or_else(|| value.len())
+}
+
 /// Returns an ArrayRef with a substring starting from `start` and with optional length `length` of each of the elements in `array`.
+/// `start` and `length` are measured in characters, so multi-byte characters are never split.
 /// `start` can be negative, in which case the start counts from the end of the string.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn substring(array: &Array, start: i64, length: &Option<u64>) -> Result<ArrayRef> {
@@ -100,15 +104,15 @@ pub fn substring(array: &Array, start: i64, length: &Option<u64>) -> Result<Arra
                 .downcast_ref::<LargeStringArray>()
                 .expect("A large string is expected"),
             start,
-            &length.map(|e| e as i64),
+            length,
         ),
         DataType::Utf8 => generic_substring(
             array
                 .as_any()
                 .downcast_ref::<StringArray>()
                 .expect("A string is expected"),
-            start as i32,
-            &length.map(|e| e as i32),
+            start,
+            length,
         ),
         _ => Err(ArrowError::ComputeError(format!(
             "substring does not support type {:?}",
@@ -268,4 +272,44 @@ mod tests {
     fn without_nulls_large_string() -> Result<()> {
         without_nulls::<LargeStringArray>()
     }
+
+    #[test]
+    fn substring_start_and_length() -> Result<()> {
+        let array = StringArray::from(vec![Some("hello"), Some("hi"), None]);
+        let result = substring(&array, 1, &Some(2))?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        let expected = StringArray::from(vec![Some("el"), Some("i"), None]);
+        assert_eq!(&expected, result);
+        Ok(())
+    }
+
+    #[test]
+    fn substring_multi_byte_chars() -> Result<()> {
+        let array = StringArray::from(vec!["héllo", "日本語", "ß"]);
+        let result = substring(&array, 1, &Some(2))?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!("él", result.value(0));
+        assert_eq!("本語", result.value(1));
+        assert_eq!("", result.value(2));
+
+        let result = substring(&array, -2, &None)?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!("lo", result.value(0));
+        assert_eq!("本語", result.value(1));
+        assert_eq!("ß", result.value(2));
+        Ok(())
+    }
+
+    #[test]
+    fn substring_sliced_array() -> Result<()> {
+        let array = StringArray::from(vec![Some("abc"), None, Some("defg"), Some("hi")]);
+        let array = array.slice(1, 3);
+        let result = substring(array.as_ref(), 1, &None)?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(3, result.len());
+        assert!(result.is_null(0));
+        assert_eq!("efg", result.value(1));
+        assert_eq!("i", result.value(2));
+        Ok(())
+    }
 }
@@ -156,6 +156,26 @@ pub fn take(
     }
 }
 
+/// Take elements from `values` at each index in `indices`, where the indices are
+/// signed 32-bit integers
+///
+/// This dispatches on the data type of `values` in the same way as `take`. The
+/// indices are bounds checked, and a negative index returns an error.
+pub fn take_dyn(values: &ArrayRef, indices: &Int32Array) -> Result<ArrayRef> {
+    let indices = indices
+        .iter()
+        .map(|index| match index {
+            Some(index) if index < 0 => Err(ArrowError::ComputeError(format!(
+                "Cannot take negative index {}",
+                index
+            ))),
+            index => Ok(index.map(|index| index as u32)),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let options = TakeOptions { check_bounds: true };
+    take(values, &UInt32Array::from(indices), Some(options))
+}
+
 /// Options that define how `take` should behave
 #[derive(Clone, Debug)]
 pub struct TakeOptions {
@@ -897,4 +917,35 @@ mod tests {
         assert_eq!(expected_keys.data_type(), result_keys.data_type());
         assert_eq!(expected_keys, result_keys);
     }
+
+    #[test]
+    fn test_take_dyn() {
+        let values: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("zero"), None, Some("two")]));
+        let indices = Int32Array::from(vec![Some(2), None, Some(1), Some(0)]);
+        let taken = take_dyn(&values, &indices).unwrap();
+        let taken = taken.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(4, taken.len());
+        assert_eq!("two", taken.value(0));
+        assert!(taken.is_null(1));
+        assert!(taken.is_null(2));
+        assert_eq!("zero", taken.value(3));
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![10, 11, 12]));
+        let indices = Int32Array::from(vec![1, 1, 2]);
+        let taken = take_dyn(&values, &indices).unwrap();
+        let taken = taken.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&[11, 11, 12], taken.value_slice(0, 3));
+    }
+
+    #[test]
+    fn test_take_dyn_invalid_indices() {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![10, 11, 12]));
+
+        let indices = Int32Array::from(vec![0, -1]);
+        assert!(take_dyn(&values, &indices).is_err());
+
+        let indices = Int32Array::from(vec![0, 3]);
+        assert!(take_dyn(&values, &indices).is_err());
+    }
 }
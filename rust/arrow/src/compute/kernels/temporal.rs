@@ -17,11 +17,11 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::Timelike;
+use chrono::{DateTime, FixedOffset, SecondsFormat, Timelike};
 
 use crate::array::*;
 use crate::datatypes::*;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 
 /// Extracts the hours of a given temporal array as an array of integers
 pub fn hour<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
@@ -52,6 +52,74 @@ where
     Ok(b.finish())
 }
 
+/// Formats a timestamp array as ISO-8601 (RFC 3339) strings in the given timezone.
+///
+/// The timezone can be `UTC`, `Z` or a fixed offset such as `+05:30`. If `tz` is `None`,
+/// the timezone of the array's data type is used, falling back to UTC. Null slots
+/// produce null strings.
+pub fn format_timestamp<T>(
+    array: &PrimitiveArray<T>,
+    tz: Option<&str>,
+) -> Result<StringArray>
+where
+    T: ArrowTimestampType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    let array_tz = match array.data_type() {
+        DataType::Timestamp(_, tz) => tz.clone(),
+        _ => None,
+    };
+    let offset = match tz.or_else(|| array_tz.as_ref().map(|s| s.as_str())) {
+        Some(tz) => parse_fixed_offset(tz)?,
+        None => FixedOffset::east(0),
+    };
+
+    let mut b = StringBuilder::new(array.len());
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            b.append_null()?;
+        } else {
+            match array.value_as_datetime(i) {
+                Some(naive) => {
+                    let dt = DateTime::<FixedOffset>::from_utc(naive, offset);
+                    b.append_value(&dt.to_rfc3339_opts(SecondsFormat::AutoSi, true))?
+                }
+                None => b.append_null()?,
+            }
+        }
+    }
+
+    Ok(b.finish())
+}
+
+/// Parses `UTC`, `Z` or a `+HH:MM` / `-HH:MM` offset into a `FixedOffset`
+fn parse_fixed_offset(tz: &str) -> Result<FixedOffset> {
+    if tz == "UTC" || tz == "Z" {
+        return Ok(FixedOffset::east(0));
+    }
+    let err = || {
+        ArrowError::ComputeError(format!(
+            "Unsupported timezone '{}', expected UTC or a fixed offset like +05:30",
+            tz
+        ))
+    };
+    let bytes = tz.as_bytes();
+    if bytes.len() != 6 || bytes[3] != b':' {
+        return Err(err());
+    }
+    let sign = match bytes[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return Err(err()),
+    };
+    let hours: i32 = tz[1..3].parse().map_err(|_| err())?;
+    let minutes: i32 = tz[4..6].parse().map_err(|_| err())?;
+    if hours > 23 || minutes > 59 {
+        return Err(err());
+    }
+    Ok(FixedOffset::east(sign * (hours * 3600 + minutes * 60)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +145,36 @@ mod tests {
         assert_eq!(10, b.value(0));
         assert_eq!(23, b.value(1));
     }
+
+    #[test]
+    fn test_format_timestamp_microsecond_utc() {
+        let a = TimestampMicrosecondArray::from_opt_vec(
+            vec![Some(1_600_000_000_123_456), None, Some(0)],
+            None,
+        );
+        let b = format_timestamp(&a, Some("UTC")).unwrap();
+        assert_eq!(3, b.len());
+        assert_eq!("2020-09-13T12:26:40.123456Z", b.value(0));
+        assert!(b.is_null(1));
+        assert_eq!("1970-01-01T00:00:00Z", b.value(2));
+    }
+
+    #[test]
+    fn test_format_timestamp_fixed_offset() {
+        let a = TimestampSecondArray::from_vec(
+            vec![0],
+            Some(std::sync::Arc::new("+05:30".to_string())),
+        );
+        let b = format_timestamp(&a, None).unwrap();
+        assert_eq!("1970-01-01T05:30:00+05:30", b.value(0));
+
+        let b = format_timestamp(&a, Some("-01:00")).unwrap();
+        assert_eq!("1969-12-31T23:00:00-01:00", b.value(0));
+    }
+
+    #[test]
+    fn test_format_timestamp_invalid_timezone() {
+        let a = TimestampSecondArray::from_vec(vec![0], None);
+        assert!(format_timestamp(&a, Some("Europe/Paris")).is_err());
+    }
 }
@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the zip kernel, which selects values from two arrays by a boolean mask.
+
+use crate::array::{
+    Array, BooleanArray, PrimitiveArray, PrimitiveArrayOps, PrimitiveBuilder,
+};
+use crate::datatypes::ArrowPrimitiveType;
+use crate::error::{ArrowError, Result};
+
+/// Returns a new array with the values of `truthy` where `mask` is true, and the values
+/// of `falsy` elsewhere, as SQL `CASE WHEN mask THEN truthy ELSE falsy END` does.
+///
+/// A null in the selected array gives a null, and a null in `mask` selects `falsy`.
+/// Returns an error if the arrays have different lengths.
+pub fn zip<T>(
+    mask: &BooleanArray,
+    truthy: &PrimitiveArray<T>,
+    falsy: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowPrimitiveType,
+{
+    if mask.len() != truthy.len() || mask.len() != falsy.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform zip on arrays of different length".to_string(),
+        ));
+    }
+
+    let mut builder = PrimitiveBuilder::<T>::new(mask.len());
+    for i in 0..mask.len() {
+        let selected = if mask.is_valid(i) && mask.value(i) {
+            truthy
+        } else {
+            falsy
+        };
+        if selected.is_null(i) {
+            builder.append_null()?;
+        } else {
+            builder.append_value(selected.value(i))?;
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float64Array, Int32Array};
+
+    #[test]
+    fn test_zip() {
+        let mask = BooleanArray::from(vec![true, false, true]);
+        let truthy = Int32Array::from(vec![1, 2, 3]);
+        let falsy = Int32Array::from(vec![9, 8, 7]);
+        let b = zip(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(Int32Array::from(vec![1, 8, 3]), b);
+
+        // nulls come from the selected side, a null mask selects `falsy`
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        let truthy = Float64Array::from(vec![None, Some(1.0), Some(2.0), Some(3.0)]);
+        let falsy = Float64Array::from(vec![Some(-1.0), None, Some(-2.0), None]);
+        let b = zip(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(
+            Float64Array::from(vec![None, None, Some(-2.0), Some(3.0)]),
+            b
+        );
+
+        let falsy = Float64Array::from(vec![1.0]);
+        assert!(zip(&mask, &truthy, &falsy).is_err());
+    }
+}
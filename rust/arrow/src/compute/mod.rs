@@ -28,7 +28,12 @@ pub use self::kernels::cast::*;
 pub use self::kernels::comparison::*;
 pub use self::kernels::concat::*;
 pub use self::kernels::filter::*;
+pub use self::kernels::join::*;
+pub use self::kernels::length::*;
 pub use self::kernels::limit::*;
+pub use self::kernels::nullif::*;
 pub use self::kernels::sort::*;
+pub use self::kernels::string::*;
 pub use self::kernels::take::*;
 pub use self::kernels::temporal::*;
+pub use self::kernels::zip::*;
@@ -153,6 +153,10 @@ impl<W: Write> Writer<W> {
                     let c = col.as_any().downcast_ref::<StringArray>().unwrap();
                     c.value(row_index).to_owned()
                 }
+                DataType::LargeUtf8 => {
+                    let c = col.as_any().downcast_ref::<LargeStringArray>().unwrap();
+                    c.value(row_index).to_owned()
+                }
                 DataType::Date32(DateUnit::Day) => {
                     let c = col.as_any().downcast_ref::<Date32Array>().unwrap();
                     c.value_as_date(row_index)
@@ -580,4 +584,36 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555000000,23:46:03\n";
         let right = writer.writer.into_inner().map(|s| s.to_string());
         assert_eq!(Some(left.to_string()), right.ok());
     }
+
+    #[test]
+    fn test_write_csv_quotes_and_nulls() {
+        let schema = Schema::new(vec![
+            Field::new("name", DataType::LargeUtf8, true),
+            Field::new("count", DataType::Int32, true),
+        ]);
+        let names = LargeStringArray::from(vec![
+            Some("plain"),
+            Some("with;delimiter"),
+            None,
+            Some("with \"quote\""),
+        ]);
+        let counts = Int32Array::from(vec![Some(1), None, Some(3), Some(-4)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(names), Arc::new(counts)],
+        )
+        .unwrap();
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut writer = WriterBuilder::new().with_delimiter(b';').build(&mut buffer);
+            writer.write(&batch).unwrap();
+            writer.write(&batch.slice(0, 1)).unwrap();
+        }
+
+        assert_eq!(
+            "name;count\nplain;1\n\"with;delimiter\";\n;3\n\"with \"\"quote\"\"\";-4\nplain;1\n",
+            String::from_utf8(buffer).unwrap()
+        );
+    }
 }
@@ -124,6 +124,11 @@ pub enum DataType {
     Utf8,
     /// A variable-length string in Unicode with UFT-8 encoding and 64-bit offsets.
     LargeUtf8,
+    /// A variable-length string in Unicode with UTF-8 encoding, stored as 16-byte views.
+    ///
+    /// Strings of up to 12 bytes are stored inline in their view, longer strings are
+    /// referenced by the index of a data buffer and an offset into it.
+    Utf8View,
     /// A list of some logical data type with variable length.
     List(Box<DataType>),
     /// A list of some logical data type with fixed length.
@@ -147,6 +152,80 @@ pub enum DataType {
     Dictionary(Box<DataType>, Box<DataType>),
 }
 
+/// The kind of a [`DataType`], ignoring its parameters such as units, child types
+/// or fields.
+///
+/// Matching on a `TypeId` only needs to compare a single byte, which makes it
+/// cheaper than matching on a `DataType` in hot dispatch loops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum TypeId {
+    /// See [`DataType::Null`]
+    Null,
+    /// See [`DataType::Boolean`]
+    Boolean,
+    /// See [`DataType::Int8`]
+    Int8,
+    /// See [`DataType::Int16`]
+    Int16,
+    /// See [`DataType::Int32`]
+    Int32,
+    /// See [`DataType::Int64`]
+    Int64,
+    /// See [`DataType::UInt8`]
+    UInt8,
+    /// See [`DataType::UInt16`]
+    UInt16,
+    /// See [`DataType::UInt32`]
+    UInt32,
+    /// See [`DataType::UInt64`]
+    UInt64,
+    /// See [`DataType::Float16`]
+    Float16,
+    /// See [`DataType::Float32`]
+    Float32,
+    /// See [`DataType::Float64`]
+    Float64,
+    /// See [`DataType::Timestamp`]
+    Timestamp,
+    /// See [`DataType::Date32`]
+    Date32,
+    /// See [`DataType::Date64`]
+    Date64,
+    /// See [`DataType::Time32`]
+    Time32,
+    /// See [`DataType::Time64`]
+    Time64,
+    /// See [`DataType::Duration`]
+    Duration,
+    /// See [`DataType::Interval`]
+    Interval,
+    /// See [`DataType::Binary`]
+    Binary,
+    /// See [`DataType::FixedSizeBinary`]
+    FixedSizeBinary,
+    /// See [`DataType::LargeBinary`]
+    LargeBinary,
+    /// See [`DataType::Utf8`]
+    Utf8,
+    /// See [`DataType::LargeUtf8`]
+    LargeUtf8,
+    /// See [`DataType::Utf8View`]
+    Utf8View,
+    /// See [`DataType::List`]
+    List,
+    /// See [`DataType::FixedSizeList`]
+    FixedSizeList,
+    /// See [`DataType::LargeList`]
+    LargeList,
+    /// See [`DataType::Struct`]
+    Struct,
+    /// See [`DataType::Union`]
+    Union,
+    /// See [`DataType::Dictionary`]
+    Dictionary,
+}
+
 /// Date is either a 32-bit or 64-bit type representing elapsed time since UNIX
 /// epoch (1970-01-01) in days or milliseconds.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -873,7 +952,7 @@ impl<T: ArrowNativeType> ToByteSlice for T {
 
 impl DataType {
     /// Parse a data type from a JSON representation
-    fn from(json: &Value) -> Result<DataType> {
+    pub(crate) fn from(json: &Value) -> Result<DataType> {
         match *json {
             Value::Object(ref map) => match map.get("name") {
                 Some(s) if s == "null" => Ok(DataType::Null),
@@ -882,6 +961,7 @@ impl DataType {
                 Some(s) if s == "largebinary" => Ok(DataType::LargeBinary),
                 Some(s) if s == "utf8" => Ok(DataType::Utf8),
                 Some(s) if s == "largeutf8" => Ok(DataType::LargeUtf8),
+                Some(s) if s == "utf8view" => Ok(DataType::Utf8View),
                 Some(s) if s == "fixedsizebinary" => {
                     // return a list with any type as its child isn't defined in the map
                     if let Some(Value::Number(size)) = map.get("byteWidth") {
@@ -1060,6 +1140,7 @@ impl DataType {
             DataType::Float64 => json!({"name": "floatingpoint", "precision": "DOUBLE"}),
             DataType::Utf8 => json!({"name": "utf8"}),
             DataType::LargeUtf8 => json!({"name": "largeutf8"}),
+            DataType::Utf8View => json!({"name": "utf8view"}),
             DataType::Binary => json!({"name": "binary"}),
             DataType::LargeBinary => json!({"name": "largebinary"}),
             DataType::FixedSizeBinary(byte_width) => {
@@ -1133,6 +1214,62 @@ impl DataType {
             _ => false,
         }
     }
+
+    /// Returns true if this type is a signed integer: (Int*)
+    pub fn is_signed_integer(&self) -> bool {
+        use DataType::*;
+        matches!(self, Int8 | Int16 | Int32 | Int64)
+    }
+
+    /// Returns true if this type is an unsigned integer: (UInt*)
+    pub fn is_unsigned_integer(&self) -> bool {
+        use DataType::*;
+        matches!(self, UInt8 | UInt16 | UInt32 | UInt64)
+    }
+
+    /// Returns true if this type is a floating point number: (Float*)
+    pub fn is_floating(&self) -> bool {
+        use DataType::*;
+        matches!(self, Float16 | Float32 | Float64)
+    }
+
+    /// Returns the kind of this type, ignoring its parameters.
+    pub fn id(&self) -> TypeId {
+        match self {
+            DataType::Null => TypeId::Null,
+            DataType::Boolean => TypeId::Boolean,
+            DataType::Int8 => TypeId::Int8,
+            DataType::Int16 => TypeId::Int16,
+            DataType::Int32 => TypeId::Int32,
+            DataType::Int64 => TypeId::Int64,
+            DataType::UInt8 => TypeId::UInt8,
+            DataType::UInt16 => TypeId::UInt16,
+            DataType::UInt32 => TypeId::UInt32,
+            DataType::UInt64 => TypeId::UInt64,
+            DataType::Float16 => TypeId::Float16,
+            DataType::Float32 => TypeId::Float32,
+            DataType::Float64 => TypeId::Float64,
+            DataType::Timestamp(_, _) => TypeId::Timestamp,
+            DataType::Date32(_) => TypeId::Date32,
+            DataType::Date64(_) => TypeId::Date64,
+            DataType::Time32(_) => TypeId::Time32,
+            DataType::Time64(_) => TypeId::Time64,
+            DataType::Duration(_) => TypeId::Duration,
+            DataType::Interval(_) => TypeId::Interval,
+            DataType::Binary => TypeId::Binary,
+            DataType::FixedSizeBinary(_) => TypeId::FixedSizeBinary,
+            DataType::LargeBinary => TypeId::LargeBinary,
+            DataType::Utf8 => TypeId::Utf8,
+            DataType::LargeUtf8 => TypeId::LargeUtf8,
+            DataType::Utf8View => TypeId::Utf8View,
+            DataType::List(_) => TypeId::List,
+            DataType::FixedSizeList(_, _) => TypeId::FixedSizeList,
+            DataType::LargeList(_) => TypeId::LargeList,
+            DataType::Struct(_) => TypeId::Struct,
+            DataType::Union(_) => TypeId::Union,
+            DataType::Dictionary(_, _) => TypeId::Dictionary,
+        }
+    }
 }
 
 impl Field {
@@ -1179,6 +1316,14 @@ impl Field {
         self.nullable
     }
 
+    /// Returns the dictionary ID, if this is a dictionary type
+    pub fn dict_id(&self) -> Option<i64> {
+        match self.data_type {
+            DataType::Dictionary(_, _) => Some(self.dict_id),
+            _ => None,
+        }
+    }
+
     /// Parse a `Field` definition from a JSON representation
     pub fn from(json: &Value) -> Result<Self> {
         match *json {
@@ -1451,7 +1596,8 @@ impl Field {
             | DataType::FixedSizeList(_, _)
             | DataType::FixedSizeBinary(_)
             | DataType::Utf8
-            | DataType::LargeUtf8 => {
+            | DataType::LargeUtf8
+            | DataType::Utf8View => {
                 if self.data_type != from.data_type {
                     return Err(ArrowError::SchemaError(
                         "Fail to merge schema Field due to conflicting datatype"
@@ -1639,6 +1785,14 @@ impl Schema {
         &self.metadata
     }
 
+    /// Compares two schemas field by field, optionally ignoring their metadata.
+    ///
+    /// `PartialEq` always takes the metadata into account.
+    pub fn equals(&self, other: &Schema, ignore_metadata: bool) -> bool {
+        self.fields == other.fields
+            && (ignore_metadata || self.metadata == other.metadata)
+    }
+
     /// Look up a column by name and return a immutable reference to the column along with
     /// it's index
     pub fn column_with_name(&self, name: &str) -> Option<(usize, &Field)> {
@@ -1723,6 +1877,54 @@ impl fmt::Display for Schema {
 /// A reference-counted reference to a [`Schema`](crate::datatypes::Schema).
 pub type SchemaRef = Arc<Schema>;
 
+/// Builds a [`Schema`](crate::datatypes::Schema) one field at a time.
+///
+/// # Example
+///
+/// ```
+/// # use arrow::datatypes::{DataType, SchemaBuilder};
+/// let schema = SchemaBuilder::new()
+///     .field("a", DataType::Int64, false)
+///     .field("b", DataType::Utf8, true)
+///     .metadata("row_count", "100")
+///     .build();
+/// assert_eq!(2, schema.fields().len());
+/// ```
+#[derive(Debug, Default)]
+pub struct SchemaBuilder {
+    fields: Vec<Field>,
+    metadata: HashMap<String, String>,
+}
+
+impl SchemaBuilder {
+    /// Creates a new builder for an empty `Schema`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a field with the given name, data type and nullability
+    pub fn field(self, name: &str, data_type: DataType, nullable: bool) -> Self {
+        self.add_field(Field::new(name, data_type, nullable))
+    }
+
+    /// Appends an existing `Field`
+    pub fn add_field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Adds a metadata key-value pair, replacing any previous value of `key`
+    pub fn metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Builds the `Schema`
+    pub fn build(self) -> Schema {
+        Schema::new_with_metadata(self.fields, self.metadata)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1903,6 +2105,96 @@ mod tests {
         assert_eq!(DataType::Int32, dt);
     }
 
+    #[test]
+    fn data_type_id_ignores_parameters() {
+        let list_int = DataType::List(Box::new(DataType::Int32));
+        let list_utf8 = DataType::List(Box::new(DataType::Utf8));
+        assert_eq!(TypeId::List, list_int.id());
+        assert_eq!(list_int.id(), list_utf8.id());
+        assert_eq!(
+            DataType::Timestamp(TimeUnit::Second, None).id(),
+            DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::new("UTC".to_string())))
+                .id()
+        );
+        assert_ne!(
+            DataType::List(Box::new(DataType::Int32)).id(),
+            DataType::LargeList(Box::new(DataType::Int32)).id()
+        );
+        assert_eq!(1, std::mem::size_of::<TypeId>());
+    }
+
+    #[test]
+    fn data_type_numeric_categories() {
+        assert!(DataType::Int16.is_signed_integer());
+        assert!(!DataType::Int16.is_unsigned_integer());
+        assert!(!DataType::Int16.is_floating());
+
+        assert!(DataType::UInt64.is_unsigned_integer());
+        assert!(!DataType::UInt64.is_signed_integer());
+        assert!(!DataType::UInt64.is_floating());
+
+        assert!(DataType::Float32.is_floating());
+        assert!(!DataType::Float32.is_signed_integer());
+        assert!(!DataType::Float32.is_unsigned_integer());
+
+        for t in &[
+            DataType::Boolean,
+            DataType::Utf8,
+            DataType::Date32(DateUnit::Day),
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Float64)),
+        ] {
+            assert!(!t.is_signed_integer());
+            assert!(!t.is_unsigned_integer());
+            assert!(!t.is_floating());
+        }
+    }
+
+    #[test]
+    fn temporal_types_json_round_trip() {
+        let units = vec![
+            TimeUnit::Second,
+            TimeUnit::Millisecond,
+            TimeUnit::Microsecond,
+            TimeUnit::Nanosecond,
+        ];
+        let mut types = vec![
+            DataType::Date32(DateUnit::Day),
+            DataType::Date64(DateUnit::Millisecond),
+            DataType::Time32(TimeUnit::Second),
+            DataType::Time32(TimeUnit::Millisecond),
+            DataType::Time64(TimeUnit::Microsecond),
+            DataType::Time64(TimeUnit::Nanosecond),
+            DataType::Interval(IntervalUnit::YearMonth),
+            DataType::Interval(IntervalUnit::DayTime),
+        ];
+        for unit in units {
+            types.push(DataType::Timestamp(unit.clone(), None));
+            types.push(DataType::Timestamp(
+                unit.clone(),
+                Some(Arc::new("America/New_York".to_string())),
+            ));
+            types.push(DataType::Duration(unit));
+        }
+
+        for data_type in types {
+            let json = data_type.to_json();
+            assert!(json.get("unit").is_some(), "{:?} has no unit", data_type);
+            let parsed = DataType::from(&json).unwrap();
+            assert_eq!(data_type, parsed, "{:?} did not round trip", json);
+        }
+
+        // the timezone is only written when it is set
+        let json = DataType::Timestamp(TimeUnit::Second, None).to_json();
+        assert!(json.get("timezone").is_none());
+        let json =
+            DataType::Timestamp(TimeUnit::Second, Some(Arc::new("+01:00".to_string())))
+                .to_json();
+        assert_eq!(
+            Some(&Value::String("+01:00".to_string())),
+            json.get("timezone")
+        );
+    }
+
     #[test]
     fn schema_json() {
         // Add some custom metadata
@@ -2526,6 +2818,62 @@ mod tests {
         assert!(schema3 != schema4);
     }
 
+    #[test]
+    fn schema_equality_metadata() {
+        let fields = vec![
+            Field::new("c1", DataType::Utf8, false),
+            Field::new("c2", DataType::Float64, true),
+        ];
+        let mut metadata = HashMap::new();
+        metadata.insert("k".to_string(), "v".to_string());
+        let schema1 = Schema::new_with_metadata(fields.clone(), metadata);
+        let schema2 = Schema::new(fields);
+
+        assert!(schema1 != schema2);
+        assert!(!schema1.equals(&schema2, false));
+        assert!(schema1.equals(&schema2, true));
+        assert!(schema1.equals(&schema1.clone(), false));
+
+        let schema3 = Schema::new(vec![Field::new("c1", DataType::Utf8, false)]);
+        assert!(!schema2.equals(&schema3, true));
+        assert!(!schema2.equals(&schema3, false));
+    }
+
+    #[test]
+    fn schema_builder() {
+        let schema = SchemaBuilder::new()
+            .field("c1", DataType::Utf8, false)
+            .field("c2", DataType::Float64, true)
+            .add_field(Field::new_dict(
+                "c3",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+                1,
+                false,
+            ))
+            .build();
+        let expected = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, false),
+            Field::new("c2", DataType::Float64, true),
+            Field::new_dict(
+                "c3",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+                1,
+                false,
+            ),
+        ]);
+        assert_eq!(expected, schema);
+
+        let schema = SchemaBuilder::new()
+            .field("c1", DataType::Utf8, false)
+            .metadata("k", "v")
+            .metadata("k", "w")
+            .build();
+        assert_eq!(Some(&"w".to_string()), schema.metadata().get("k"));
+        assert_eq!(1, schema.metadata().len());
+    }
+
     #[test]
     fn test_arrow_native_type_to_json() {
         assert_eq!(Some(Bool(true)), true.into_json_value());
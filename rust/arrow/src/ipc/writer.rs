@@ -24,7 +24,7 @@ use std::io::{BufWriter, Write};
 
 use flatbuffers::FlatBufferBuilder;
 
-use crate::array::ArrayDataRef;
+use crate::array::{ArrayDataRef, ArrayRef};
 use crate::buffer::{Buffer, MutableBuffer};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
@@ -476,6 +476,61 @@ pub fn record_batch_to_bytes(
     }
 }
 
+/// Write a dictionary's values into a tuple of bytes, one for the header (ipc::Message)
+/// and the other for the dictionary's data
+pub fn dictionary_batch_to_bytes(
+    dict_id: i64,
+    values: &ArrayRef,
+    write_options: &IpcWriteOptions,
+) -> EncodedData {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let mut nodes: Vec<ipc::FieldNode> = vec![];
+    let mut buffers: Vec<ipc::Buffer> = vec![];
+    let mut arrow_data: Vec<u8> = vec![];
+    write_array_data(
+        &values.data(),
+        &mut buffers,
+        &mut arrow_data,
+        &mut nodes,
+        0,
+        values.len(),
+        values.null_count(),
+    );
+
+    // write data
+    let buffers = fbb.create_vector(&buffers);
+    let nodes = fbb.create_vector(&nodes);
+
+    let root = {
+        let mut batch_builder = ipc::RecordBatchBuilder::new(&mut fbb);
+        batch_builder.add_length(values.len() as i64);
+        batch_builder.add_nodes(nodes);
+        batch_builder.add_buffers(buffers);
+        batch_builder.finish()
+    };
+    let root = {
+        let mut dictionary_builder = ipc::DictionaryBatchBuilder::new(&mut fbb);
+        dictionary_builder.add_id(dict_id);
+        dictionary_builder.add_data(root);
+        dictionary_builder.finish().as_union_value()
+    };
+    // create an ipc::Message
+    let mut message = ipc::MessageBuilder::new(&mut fbb);
+    message.add_version(write_options.metadata_version);
+    message.add_header_type(ipc::MessageHeader::DictionaryBatch);
+    message.add_bodyLength(arrow_data.len() as i64);
+    message.add_header(root);
+    let root = message.finish();
+    fbb.finish(root, None);
+    let finished_data = fbb.finished_data();
+
+    EncodedData {
+        ipc_message: finished_data.to_vec(),
+        arrow_data,
+    }
+}
+
 /// Write a record batch to the writer, writing the message size before the message
 /// if the record batch is being written to a stream
 fn write_continuation<W: Write>(
@@ -545,6 +600,13 @@ fn write_array_data(
         offset = write_buffer(buffer, &mut buffers, &mut arrow_data, offset);
     });
 
+    // dictionary values are not part of the record batch: the IPC format sends them
+    // in separate dictionary batches, and readers only expect the nodes and buffers of
+    // the keys here. Writing them would shift the nodes of the following columns.
+    if let DataType::Dictionary(_, _) = array_data.data_type() {
+        return offset;
+    }
+
     // recursively write out nested structures
     array_data.child_data().iter().for_each(|data_ref| {
         // write the nested data (e.g list data)
@@ -694,6 +756,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_record_batch_to_bytes_skips_dictionary_values() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new_dict("a", dict_type, true, 0, false),
+            Field::new("b", DataType::Int32, true),
+        ]));
+        let dict_array: DictionaryArray<Int8Type> =
+            vec!["x", "y", "x"].into_iter().collect();
+        let dict_values = dict_array.values();
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(dict_array),
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+            ],
+        )
+        .unwrap();
+
+        let data = record_batch_to_bytes(&batch, &IpcWriteOptions::default());
+        let message = ipc::get_root_as_message(&data.ipc_message);
+        let ipc_batch = message.header_as_record_batch().unwrap();
+        // one node per column, without a node for the dictionary values
+        assert_eq!(2, ipc_batch.nodes().unwrap().len());
+
+        let decoded = read_record_batch(
+            &data.arrow_data,
+            ipc_batch,
+            schema,
+            &[Some(dict_values), None],
+        )
+        .unwrap();
+        let b = decoded
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&[1, 2, 3], b.value_slice(0, 3));
+    }
+
     #[test]
     fn read_and_rewrite_generated_files() {
         let testdata = env::var("ARROW_TEST_DATA").expect("ARROW_TEST_DATA not defined");
@@ -15,9 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Transfer data between the Arrow memory format and JSON line-delimited records.
+//! Transfer data between the Arrow memory format and JSON line-delimited records, and
+//! read and write the JSON format of the Arrow integration tests.
 
 pub mod reader;
+pub mod writer;
 
+pub use self::reader::ArrowJsonReader;
 pub use self::reader::Reader;
 pub use self::reader::ReaderBuilder;
+pub use self::writer::ArrowJsonWriter;
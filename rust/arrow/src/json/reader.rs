@@ -52,7 +52,8 @@ use serde_json::Value;
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
-use crate::record_batch::RecordBatch;
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+use crate::util::integration_util::{ArrowJson, ArrowJsonBatch};
 
 /// Coerce data type during inference
 ///
@@ -853,6 +854,50 @@ impl ReaderBuilder {
     }
 }
 
+/// Reader of record batches in the JSON format of the Arrow integration tests, as
+/// written by [`ArrowJsonWriter`](crate::json::writer::ArrowJsonWriter).
+///
+/// The whole document is parsed up front, and its batches are converted to record
+/// batches as they are iterated over.
+#[derive(Debug)]
+pub struct ArrowJsonReader {
+    /// The schema of the batches
+    schema: SchemaRef,
+    /// The batches left to read
+    batches: std::vec::IntoIter<ArrowJsonBatch>,
+}
+
+impl ArrowJsonReader {
+    /// Parses a document in the JSON format of the Arrow integration tests.
+    pub fn try_new<R: Read>(reader: R) -> Result<Self> {
+        let json: ArrowJson = serde_json::from_reader(reader)
+            .map_err(|e| ArrowError::JsonError(e.to_string()))?;
+        let mut schema = serde_json::Map::new();
+        schema.insert("fields".to_string(), Value::Array(json.schema.fields));
+        let schema = Schema::from(&Value::Object(schema))?;
+        Ok(ArrowJsonReader {
+            schema: Arc::new(schema),
+            batches: json.batches.into_iter(),
+        })
+    }
+}
+
+impl Iterator for ArrowJsonReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.batches
+            .next()
+            .map(|batch| batch.to_batch(self.schema.clone()))
+    }
+}
+
+impl RecordBatchReader for ArrowJsonReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::datatypes::DataType::Dictionary;
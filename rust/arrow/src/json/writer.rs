@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Writer for the JSON format of the Arrow integration tests.
+//!
+//! Unlike the line-delimited records of the JSON [`Reader`](crate::json::Reader), this
+//! format describes the physical layout of each column, with its `VALIDITY`, `DATA` and
+//! `OFFSET` buffers and its `children`, and is used to check the conformance of Arrow
+//! implementations.
+//!
+//! Example:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::Int32Array;
+//! use arrow::datatypes::{DataType, Field, Schema};
+//! use arrow::json::writer::ArrowJsonWriter;
+//! use arrow::record_batch::RecordBatch;
+//!
+//! let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+//! let a = Int32Array::from(vec![Some(1), None]);
+//! let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a)]).unwrap();
+//!
+//! let mut writer = ArrowJsonWriter::new(vec![], schema);
+//! writer.write(&batch).unwrap();
+//! let json = String::from_utf8(writer.finish().unwrap()).unwrap();
+//! assert!(json.contains(r#""VALIDITY":[1,0],"DATA":[1,0]"#));
+//! ```
+
+use std::io::Write;
+
+use crate::datatypes::SchemaRef;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+use crate::util::integration_util::{ArrowJson, ArrowJsonBatch, ArrowJsonSchema};
+
+/// Writes record batches in the JSON format of the Arrow integration tests.
+///
+/// As the format is a single JSON document, the batches are buffered and only written
+/// out by [`ArrowJsonWriter::finish`].
+///
+/// Boolean, numeric, `Utf8`, `LargeUtf8` and `List` columns are supported.
+#[derive(Debug)]
+pub struct ArrowJsonWriter<W: Write> {
+    /// The underlying writer
+    writer: W,
+    /// The schema of the batches
+    schema: SchemaRef,
+    /// The batches written so far
+    batches: Vec<ArrowJsonBatch>,
+}
+
+impl<W: Write> ArrowJsonWriter<W> {
+    /// Creates a new writer of batches with the given schema.
+    pub fn new(writer: W, schema: SchemaRef) -> Self {
+        ArrowJsonWriter {
+            writer,
+            schema,
+            batches: vec![],
+        }
+    }
+
+    /// Writes a record batch, returning an error if its schema differs from the schema
+    /// of the writer.
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.schema() != self.schema {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot write a record batch with a different schema".to_string(),
+            ));
+        }
+        self.batches.push(ArrowJsonBatch::from_batch(batch)?);
+        Ok(())
+    }
+
+    /// Writes the schema and the batches to the underlying writer, and returns it.
+    pub fn finish(mut self) -> Result<W> {
+        let json = ArrowJson {
+            schema: ArrowJsonSchema {
+                fields: self.schema.fields().iter().map(|f| f.to_json()).collect(),
+            },
+            batches: self.batches,
+            dictionaries: None,
+        };
+        serde_json::to_writer(&mut self.writer, &json)
+            .map_err(|e| ArrowError::JsonError(e.to_string()))?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use crate::array::*;
+    use crate::datatypes::{DataType, Field, Schema};
+    use crate::json::reader::ArrowJsonReader;
+    use crate::record_batch::RecordBatchReader;
+
+    #[test]
+    fn test_write_read_mixed_batch() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int64, false),
+            Field::new("c", DataType::Float64, true),
+            Field::new("d", DataType::Utf8, true),
+            Field::new("e", DataType::List(Box::new(DataType::Int32)), true),
+        ]));
+
+        let mut list_builder = ListBuilder::new(Int32Builder::new(4));
+        list_builder.values().append_value(1).unwrap();
+        list_builder.values().append_value(2).unwrap();
+        list_builder.append(true).unwrap();
+        list_builder.append(false).unwrap();
+        list_builder.values().append_null().unwrap();
+        list_builder.append(true).unwrap();
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(Int64Array::from(vec![-1, 0, 1 << 40])),
+            Arc::new(Float64Array::from(vec![Some(0.5), Some(1.5), None])),
+            Arc::new(StringArray::from(vec![Some("x"), None, Some("yz")])),
+            Arc::new(list_builder.finish()),
+        ];
+        let batch = RecordBatch::try_new(schema.clone(), columns).unwrap();
+
+        let mut writer = ArrowJsonWriter::new(vec![], schema.clone());
+        writer.write(&batch).unwrap();
+        let buf = writer.finish().unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(
+            json["schema"]["fields"][4],
+            schema.field(4).to_json(),
+            "the schema is written as in the integration format"
+        );
+        let json_batch = &json["batches"][0];
+        assert_eq!(json!(3), json_batch["count"]);
+        assert_eq!(
+            json!({"name": "a", "count": 3, "VALIDITY": [1, 0, 1], "DATA": [1, 0, 3]}),
+            json_batch["columns"][0]
+        );
+        assert_eq!(
+            json!({
+                "name": "b",
+                "count": 3,
+                "VALIDITY": [1, 1, 1],
+                "DATA": ["-1", "0", "1099511627776"]
+            }),
+            json_batch["columns"][1]
+        );
+        assert_eq!(
+            json!({
+                "name": "d",
+                "count": 3,
+                "VALIDITY": [1, 0, 1],
+                "OFFSET": [0, 1, 1, 3],
+                "DATA": ["x", "", "yz"]
+            }),
+            json_batch["columns"][3]
+        );
+        assert_eq!(
+            json!({
+                "name": "e",
+                "count": 3,
+                "VALIDITY": [1, 0, 1],
+                "OFFSET": [0, 2, 2, 3],
+                "children": [{
+                    "name": "item",
+                    "count": 3,
+                    "VALIDITY": [1, 1, 0],
+                    "DATA": [1, 2, 0]
+                }]
+            }),
+            json_batch["columns"][4]
+        );
+
+        let mut reader = ArrowJsonReader::try_new(&buf[..]).unwrap();
+        assert_eq!(schema, reader.schema());
+        let read = reader.next().unwrap().unwrap();
+        assert!(reader.next().is_none());
+        assert_column_eq::<Int32Array>(read.column(0), batch.column(0));
+        assert_column_eq::<Int64Array>(read.column(1), batch.column(1));
+        assert_column_eq::<Float64Array>(read.column(2), batch.column(2));
+        assert_column_eq::<StringArray>(read.column(3), batch.column(3));
+        assert_column_eq::<ListArray>(read.column(4), batch.column(4));
+    }
+
+    fn assert_column_eq<T: ArrayEqual + 'static>(left: &ArrayRef, right: &ArrayRef) {
+        let left = left.as_any().downcast_ref::<T>().unwrap();
+        assert!(
+            left.equals(right.as_ref()),
+            "{:?} differs after a round trip",
+            right
+        );
+    }
+}
@@ -141,5 +141,6 @@ pub mod ipc;
 pub mod json;
 pub mod memory;
 pub mod record_batch;
+pub mod scalar;
 pub mod tensor;
 pub mod util;
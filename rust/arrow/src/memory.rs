@@ -21,6 +21,15 @@
 use std::alloc::Layout;
 use std::mem::align_of;
 use std::ptr::NonNull;
+#[cfg(feature = "buffer_pool")]
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicIsize, Ordering},
+    sync::Mutex,
+};
+
+#[cfg(feature = "buffer_pool")]
+use crate::buffer::MutableBuffer;
 
 // NOTE: Below code is written for spatial/temporal prefetcher optimizations. Memory allocation
 // should align well with usage pattern of cache access and block sizes on layers of storage levels from
@@ -135,6 +144,26 @@ const FALLBACK_ALIGNMENT: usize = 1 << 6;
 /// If you use allocation methods shown here you won't have any problems.
 const BYPASS_PTR: NonNull<u8> = unsafe { NonNull::new_unchecked(ALIGNMENT as *mut u8) };
 
+/// The number of bytes currently allocated through this module
+#[cfg(feature = "buffer_pool")]
+static ALLOCATED_BYTES: AtomicIsize = AtomicIsize::new(0);
+
+/// Returns the number of bytes currently allocated by `allocate_aligned` and
+/// `reallocate` and not yet freed, across all threads.
+///
+/// This can be used to observe whether a [`BufferPool`] serves requests without
+/// allocating.
+#[cfg(feature = "buffer_pool")]
+pub fn allocated_bytes() -> isize {
+    ALLOCATED_BYTES.load(Ordering::Acquire)
+}
+
+#[inline]
+fn track_allocation(_bytes: isize) {
+    #[cfg(feature = "buffer_pool")]
+    ALLOCATED_BYTES.fetch_add(_bytes, Ordering::AcqRel);
+}
+
 pub fn allocate_aligned(size: usize) -> *mut u8 {
     unsafe {
         if size == 0 {
@@ -144,7 +173,11 @@ pub fn allocate_aligned(size: usize) -> *mut u8 {
             BYPASS_PTR.as_ptr()
         } else {
             let layout = Layout::from_size_align_unchecked(size, ALIGNMENT);
-            std::alloc::alloc_zeroed(layout)
+            let ptr = std::alloc::alloc_zeroed(layout);
+            if !ptr.is_null() {
+                track_allocation(size as isize);
+            }
+            ptr
         }
     }
 }
@@ -160,6 +193,7 @@ pub fn allocate_aligned(size: usize) -> *mut u8 {
 pub unsafe fn free_aligned(ptr: *mut u8, size: usize) {
     if ptr != BYPASS_PTR.as_ptr() {
         std::alloc::dealloc(ptr, Layout::from_size_align_unchecked(size, ALIGNMENT));
+        track_allocation(-(size as isize));
     }
 }
 
@@ -190,8 +224,11 @@ pub unsafe fn reallocate(ptr: *mut u8, old_size: usize, new_size: usize) -> *mut
         new_size,
     );
 
-    if !new_ptr.is_null() && new_size > old_size {
-        new_ptr.add(old_size).write_bytes(0, new_size - old_size);
+    if !new_ptr.is_null() {
+        track_allocation(new_size as isize - old_size as isize);
+        if new_size > old_size {
+            new_ptr.add(old_size).write_bytes(0, new_size - old_size);
+        }
     }
 
     new_ptr
@@ -231,10 +268,138 @@ pub fn is_ptr_aligned<T>(p: *const T) -> bool {
     p.align_offset(align_of::<T>()) == 0
 }
 
+/// A pool of [`MutableBuffer`](crate::buffer::MutableBuffer)s that can be handed out
+/// again instead of being freed, reducing allocator pressure when buffers of similar
+/// capacities are repeatedly allocated, e.g. when building one batch after another.
+///
+/// Released buffers are bucketed by capacity, and at most `max_buffers_per_capacity`
+/// buffers are kept per bucket; any further released buffers are simply dropped.
+#[cfg(feature = "buffer_pool")]
+#[derive(Debug)]
+pub struct BufferPool {
+    buffers: Mutex<BTreeMap<usize, Vec<MutableBuffer>>>,
+    max_buffers_per_capacity: usize,
+}
+
+#[cfg(feature = "buffer_pool")]
+impl BufferPool {
+    /// Creates an empty pool keeping at most `max_buffers_per_capacity` buffers of
+    /// each capacity
+    pub fn new(max_buffers_per_capacity: usize) -> Self {
+        Self {
+            buffers: Mutex::new(BTreeMap::new()),
+            max_buffers_per_capacity,
+        }
+    }
+
+    /// Returns an empty, zeroed buffer with a capacity of at least `capacity` bytes.
+    ///
+    /// The smallest pooled buffer that is large enough is reused, a new buffer is
+    /// allocated if there is none.
+    pub fn acquire(&self, capacity: usize) -> MutableBuffer {
+        let pooled = {
+            let mut buffers = self.buffers.lock().unwrap();
+            let key = buffers.range(capacity..).next().map(|(key, _)| *key);
+            key.map(|key| {
+                let bucket = buffers.get_mut(&key).unwrap();
+                let buffer = bucket.pop().unwrap();
+                if bucket.is_empty() {
+                    buffers.remove(&key);
+                }
+                buffer
+            })
+        };
+        match pooled {
+            // zero the buffer outside of the lock, as it is no longer shared
+            Some(mut buffer) => {
+                let capacity = buffer.capacity();
+                buffer.clear();
+                buffer.set_null_bits(0, capacity);
+                buffer
+            }
+            None => MutableBuffer::new(capacity),
+        }
+    }
+
+    /// Returns `buffer` to the pool so that it can be handed out by `acquire`.
+    ///
+    /// The buffer is dropped if the pool already holds `max_buffers_per_capacity`
+    /// buffers of its capacity.
+    pub fn release(&self, buffer: MutableBuffer) {
+        let capacity = buffer.capacity();
+        if capacity == 0 || self.max_buffers_per_capacity == 0 {
+            return;
+        }
+
+        let mut buffers = self.buffers.lock().unwrap();
+        let bucket = buffers.entry(capacity).or_insert_with(Vec::new);
+        if bucket.len() < self.max_buffers_per_capacity {
+            bucket.push(buffer);
+        }
+    }
+
+    /// Returns the number of buffers currently held by the pool
+    pub fn len(&self) -> usize {
+        self.buffers.lock().unwrap().values().map(|b| b.len()).sum()
+    }
+
+    /// Returns whether the pool holds no buffers
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "buffer_pool")]
+    #[test]
+    fn test_buffer_pool_reuse() {
+        use std::io::Write;
+
+        let pool = BufferPool::new(2);
+        let mut buffer = pool.acquire(100);
+        assert!(buffer.capacity() >= 100);
+        buffer.write_all(&[1, 2, 3]).unwrap();
+        let ptr = buffer.raw_data();
+        let capacity = buffer.capacity();
+
+        pool.release(buffer);
+        assert_eq!(1, pool.len());
+
+        // a smaller request is served by the pooled buffer, which was reset
+        let buffer = pool.acquire(64);
+        assert_eq!(ptr, buffer.raw_data());
+        assert_eq!(capacity, buffer.capacity());
+        assert_eq!(0, buffer.len());
+        let bytes = unsafe { std::slice::from_raw_parts(buffer.raw_data(), 3) };
+        assert_eq!(&[0, 0, 0], bytes);
+        assert!(pool.is_empty());
+
+        // a larger request cannot be served from the pool
+        pool.release(buffer);
+        let larger = pool.acquire(capacity + 1);
+        assert!(larger.capacity() > capacity);
+        assert_eq!(1, pool.len());
+    }
+
+    #[cfg(feature = "buffer_pool")]
+    #[test]
+    fn test_buffer_pool_limit() {
+        let pool = BufferPool::new(1);
+        pool.release(MutableBuffer::new(64));
+        pool.release(MutableBuffer::new(64));
+        pool.release(MutableBuffer::new(0));
+        assert_eq!(1, pool.len());
+
+        // a pool that keeps no buffers always allocates
+        let pool = BufferPool::new(0);
+        pool.release(MutableBuffer::new(64));
+        assert!(pool.is_empty());
+        assert_eq!(64, pool.acquire(64).capacity());
+    }
+
     #[test]
     fn test_allocate() {
         for _ in 0..10 {
@@ -184,6 +184,115 @@ impl RecordBatch {
     pub fn columns(&self) -> &[ArrayRef] {
         &self.columns[..]
     }
+
+    /// Returns the number of null values of each column.
+    ///
+    /// The null count of a sliced column only covers the slice, so this is also
+    /// correct for batches of sliced arrays.
+    pub fn null_counts(&self) -> Vec<i64> {
+        self.columns
+            .iter()
+            .map(|column| column.null_count() as i64)
+            .collect()
+    }
+
+    /// Returns a summary of the record batch with its number of rows and the name,
+    /// data type and null count of each column.
+    pub fn summary(&self) -> RecordBatchSummary {
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(self.null_counts())
+            .map(|(field, null_count)| ColumnSummary {
+                name: field.name().clone(),
+                data_type: field.data_type().clone(),
+                null_count,
+            })
+            .collect();
+        RecordBatchSummary {
+            num_rows: self.num_rows(),
+            columns,
+        }
+    }
+
+    /// Returns a new `RecordBatch` with the same columns and the given schema, for
+    /// instance to rename the columns or attach metadata.
+    ///
+    /// The columns are shared with this batch rather than copied. Returns an error if
+    /// the schema does not match the number and data types of the columns.
+    pub fn with_schema(&self, schema: SchemaRef) -> Result<RecordBatch> {
+        RecordBatch::try_new(schema, self.columns.clone())
+    }
+
+    /// Returns a zero-copy slice of this batch with `length` rows starting at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length` is greater than the number of rows.
+    pub fn slice(&self, offset: usize, length: usize) -> RecordBatch {
+        assert!(
+            offset + length <= self.num_rows(),
+            "the offset and length of the slice exceed the number of rows"
+        );
+        RecordBatch {
+            schema: self.schema.clone(),
+            columns: self
+                .columns
+                .iter()
+                .map(|column| column.slice(offset, length))
+                .collect(),
+        }
+    }
+
+    /// Returns true if `other` has an equal schema and the same values in each column.
+    ///
+    /// Columns are compared logically, so sliced columns are equal to columns built
+    /// with just the values of the slice.
+    pub fn equals(&self, other: &RecordBatch) -> bool {
+        self.schema == other.schema && self.columns_equal(other)
+    }
+
+    /// Like [`RecordBatch::equals`], but ignores the metadata of the two schemas and
+    /// only compares their fields.
+    pub fn equals_ignore_metadata(&self, other: &RecordBatch) -> bool {
+        self.schema.fields() == other.schema.fields() && self.columns_equal(other)
+    }
+
+    fn columns_equal(&self, other: &RecordBatch) -> bool {
+        self.num_rows() == other.num_rows()
+            && self
+                .columns
+                .iter()
+                .zip(other.columns.iter())
+                .all(|(a, b)| a.range_equals(b.as_ref(), 0, a.len(), 0))
+    }
+
+    /// Returns an iterator over the rows of this batch, each as a zero-copy single-row
+    /// slice of the batch.
+    pub fn rows(&self) -> impl Iterator<Item = RecordBatch> + '_ {
+        (0..self.num_rows()).map(move |i| self.slice(i, 1))
+    }
+}
+
+/// A summary of a [`RecordBatch`], as returned by [`RecordBatch::summary`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordBatchSummary {
+    /// The number of rows of the batch
+    pub num_rows: usize,
+    /// The summary of each column of the batch
+    pub columns: Vec<ColumnSummary>,
+}
+
+/// A summary of a column of a [`RecordBatch`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnSummary {
+    /// The name of the column's field
+    pub name: String,
+    /// The data type of the column
+    pub data_type: DataType,
+    /// The number of null values in the column
+    pub null_count: i64,
 }
 
 impl From<&StructArray> for RecordBatch {
@@ -285,6 +394,192 @@ mod tests {
         assert!(!batch.is_ok());
     }
 
+    #[test]
+    fn record_batch_with_schema() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = StringArray::from(vec!["x", "y", "z"]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)])
+                .unwrap();
+
+        let renamed = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, false),
+        ]);
+        let renamed = batch.with_schema(Arc::new(renamed)).unwrap();
+        assert_eq!("id", renamed.schema().field(0).name());
+        assert_eq!("name", renamed.schema().field(1).name());
+        assert_eq!(3, renamed.num_rows());
+        // the columns are shared rather than copied
+        assert!(Arc::ptr_eq(batch.column(0), renamed.column(0)));
+        assert!(Arc::ptr_eq(batch.column(1), renamed.column(1)));
+
+        let mismatched = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+        ]);
+        assert!(batch.with_schema(Arc::new(mismatched)).is_err());
+
+        let too_few = Schema::new(vec![Field::new("id", DataType::Int32, false)]);
+        assert!(batch.with_schema(Arc::new(too_few)).is_err());
+    }
+
+    #[test]
+    fn record_batch_slice() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let b = StringArray::from(vec!["w", "x", "y", "z"]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)])
+                .unwrap();
+
+        let sliced = batch.slice(1, 2);
+        assert_eq!(2, sliced.num_rows());
+        assert_eq!(batch.schema(), sliced.schema());
+        let a = sliced
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        let b = sliced
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert!(a.is_null(0));
+        assert_eq!(3, a.value(1));
+        assert_eq!("x", b.value(0));
+        assert_eq!("y", b.value(1));
+
+        assert_eq!(0, batch.slice(4, 0).num_rows());
+    }
+
+    #[test]
+    #[should_panic(expected = "exceed the number of rows")]
+    fn record_batch_slice_out_of_bounds() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a)]).unwrap();
+        batch.slice(2, 2);
+    }
+
+    #[test]
+    fn record_batch_rows() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = StringArray::from(vec!["x", "y", "z"]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)])
+                .unwrap();
+
+        let rows: Vec<RecordBatch> = batch.rows().collect();
+        assert_eq!(3, rows.len());
+        let expected_a = vec![Some(1), None, Some(3)];
+        let expected_b = vec!["x", "y", "z"];
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(1, row.num_rows());
+            assert_eq!(2, row.num_columns());
+            let a = row.column(0).as_any().downcast_ref::<Int32Array>().unwrap();
+            let b = row
+                .column(1)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            assert_eq!(expected_a[i], a.get(0));
+            assert_eq!(expected_b[i], b.value(0));
+        }
+    }
+
+    #[test]
+    fn record_batch_equals() {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ];
+        let schema = Arc::new(Schema::new(fields.clone()));
+        let make_batch = |a: Vec<Option<i32>>, b: Vec<&str>| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(a)),
+                    Arc::new(StringArray::from(b)),
+                ],
+            )
+            .unwrap()
+        };
+
+        let batch = make_batch(vec![Some(1), None, Some(3)], vec!["x", "y", "z"]);
+        let same = make_batch(vec![Some(1), None, Some(3)], vec!["x", "y", "z"]);
+        let different = make_batch(vec![Some(1), None, Some(3)], vec!["x", "w", "z"]);
+        assert!(batch.equals(&same));
+        assert!(!batch.equals(&different));
+
+        // sliced columns are compared by their logical values
+        let tail = make_batch(vec![None, Some(3)], vec!["y", "z"]);
+        assert!(batch.slice(1, 2).equals(&tail));
+        assert!(!batch.slice(0, 2).equals(&tail));
+
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("origin".to_string(), "test".to_string());
+        let with_metadata = same
+            .with_schema(Arc::new(Schema::new_with_metadata(fields, metadata)))
+            .unwrap();
+        assert!(!batch.equals(&with_metadata));
+        assert!(batch.equals_ignore_metadata(&with_metadata));
+    }
+
+    #[test]
+    fn record_batch_null_counts() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let a = Int32Array::from(vec![None, Some(1), None, Some(3), None]);
+        let b = StringArray::from(vec![Some("v"), None, Some("x"), Some("y"), Some("z")]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(a), Arc::new(b)],
+        )
+        .unwrap();
+        assert_eq!(vec![3, 1], batch.null_counts());
+
+        // a batch of slices only counts the nulls within the slices
+        let sliced = RecordBatch::try_new(
+            Arc::new(schema),
+            batch.columns().iter().map(|c| c.slice(2, 3)).collect(),
+        )
+        .unwrap();
+        assert_eq!(vec![2, 0], sliced.null_counts());
+
+        let summary = sliced.summary();
+        assert_eq!(3, summary.num_rows);
+        assert_eq!(
+            vec![
+                ColumnSummary {
+                    name: "a".to_string(),
+                    data_type: DataType::Int32,
+                    null_count: 2,
+                },
+                ColumnSummary {
+                    name: "b".to_string(),
+                    data_type: DataType::Utf8,
+                    null_count: 0,
+                },
+            ],
+            summary.columns
+        );
+    }
+
     #[test]
     fn create_record_batch_record_mismatch() {
         let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
@@ -0,0 +1,379 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`ScalarValue`], a single value of one of the primitive Arrow types.
+
+use std::sync::Arc;
+
+use num::{NumCast, Zero};
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// A single value of one of the primitive Arrow types, or a null value.
+///
+/// This is returned by kernels that reduce an array of an arbitrary type to a
+/// single value, such as [`sum_dyn`](crate::compute::kernels::aggregate::sum_dyn).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScalarValue {
+    /// A null value
+    Null,
+    /// A boolean value
+    Boolean(bool),
+    /// A signed 8-bit integer
+    Int8(i8),
+    /// A signed 16-bit integer
+    Int16(i16),
+    /// A signed 32-bit integer
+    Int32(i32),
+    /// A signed 64-bit integer
+    Int64(i64),
+    /// An unsigned 8-bit integer
+    UInt8(u8),
+    /// An unsigned 16-bit integer
+    UInt16(u16),
+    /// An unsigned 32-bit integer
+    UInt32(u32),
+    /// An unsigned 64-bit integer
+    UInt64(u64),
+    /// A 32-bit floating point number
+    Float32(f32),
+    /// A 64-bit floating point number
+    Float64(f64),
+}
+
+impl ScalarValue {
+    /// Returns the data type of the value.
+    pub fn data_type(&self) -> DataType {
+        match self {
+            ScalarValue::Null => DataType::Null,
+            ScalarValue::Boolean(_) => DataType::Boolean,
+            ScalarValue::Int8(_) => DataType::Int8,
+            ScalarValue::Int16(_) => DataType::Int16,
+            ScalarValue::Int32(_) => DataType::Int32,
+            ScalarValue::Int64(_) => DataType::Int64,
+            ScalarValue::UInt8(_) => DataType::UInt8,
+            ScalarValue::UInt16(_) => DataType::UInt16,
+            ScalarValue::UInt32(_) => DataType::UInt32,
+            ScalarValue::UInt64(_) => DataType::UInt64,
+            ScalarValue::Float32(_) => DataType::Float32,
+            ScalarValue::Float64(_) => DataType::Float64,
+        }
+    }
+
+    /// Returns whether the value is null.
+    pub fn is_null(&self) -> bool {
+        *self == ScalarValue::Null
+    }
+
+    /// Returns whether values of `data_type` can be represented as a `ScalarValue`.
+    pub fn supports_type(data_type: &DataType) -> bool {
+        use DataType::*;
+        match data_type {
+            Null | Boolean | Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32
+            | UInt64 | Float32 | Float64 => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the value of `array` at `index`, which is [`ScalarValue::Null`] if the
+    /// slot is null.
+    ///
+    /// Returns an error if the type of `array` is not supported.
+    pub fn try_from_array(array: &Array, index: usize) -> Result<ScalarValue> {
+        macro_rules! typed_value {
+            ($ARRAY_TYPE:ident) => {{
+                let array = array.as_any().downcast_ref::<$ARRAY_TYPE>().unwrap();
+                if array.is_null(index) {
+                    ScalarValue::Null
+                } else {
+                    ScalarValue::from(array.value(index))
+                }
+            }};
+        }
+
+        Ok(match array.data_type() {
+            DataType::Null => ScalarValue::Null,
+            DataType::Boolean => typed_value!(BooleanArray),
+            DataType::Int8 => typed_value!(Int8Array),
+            DataType::Int16 => typed_value!(Int16Array),
+            DataType::Int32 => typed_value!(Int32Array),
+            DataType::Int64 => typed_value!(Int64Array),
+            DataType::UInt8 => typed_value!(UInt8Array),
+            DataType::UInt16 => typed_value!(UInt16Array),
+            DataType::UInt32 => typed_value!(UInt32Array),
+            DataType::UInt64 => typed_value!(UInt64Array),
+            DataType::Float32 => typed_value!(Float32Array),
+            DataType::Float64 => typed_value!(Float64Array),
+            other => {
+                return Err(ArrowError::ComputeError(format!(
+                    "Cannot create a scalar value from an array of type {:?}",
+                    other
+                )))
+            }
+        })
+    }
+
+    /// Converts the value to `data_type`.
+    ///
+    /// Booleans convert to 1 or 0 and numbers convert to `true` unless they are 0. A
+    /// null value converts to null of any supported type.
+    ///
+    /// Returns an error if `data_type` is not supported, or if the value does not fit
+    /// into it, such as a negative number into an unsigned integer.
+    pub fn cast_to(&self, data_type: &DataType) -> Result<ScalarValue> {
+        fn cast_number<N: NumCast + Zero>(
+            value: N,
+            data_type: &DataType,
+        ) -> Option<ScalarValue> {
+            match data_type {
+                DataType::Boolean => Some(ScalarValue::Boolean(!value.is_zero())),
+                DataType::Int8 => num::cast(value).map(ScalarValue::Int8),
+                DataType::Int16 => num::cast(value).map(ScalarValue::Int16),
+                DataType::Int32 => num::cast(value).map(ScalarValue::Int32),
+                DataType::Int64 => num::cast(value).map(ScalarValue::Int64),
+                DataType::UInt8 => num::cast(value).map(ScalarValue::UInt8),
+                DataType::UInt16 => num::cast(value).map(ScalarValue::UInt16),
+                DataType::UInt32 => num::cast(value).map(ScalarValue::UInt32),
+                DataType::UInt64 => num::cast(value).map(ScalarValue::UInt64),
+                DataType::Float32 => num::cast(value).map(ScalarValue::Float32),
+                DataType::Float64 => num::cast(value).map(ScalarValue::Float64),
+                _ => None,
+            }
+        }
+
+        let value = match self {
+            ScalarValue::Null if Self::supports_type(data_type) => {
+                Some(ScalarValue::Null)
+            }
+            ScalarValue::Null => None,
+            ScalarValue::Boolean(v) if *data_type == DataType::Boolean => {
+                Some(ScalarValue::Boolean(*v))
+            }
+            ScalarValue::Boolean(v) => cast_number(*v as u8, data_type),
+            ScalarValue::Int8(v) => cast_number(*v, data_type),
+            ScalarValue::Int16(v) => cast_number(*v, data_type),
+            ScalarValue::Int32(v) => cast_number(*v, data_type),
+            ScalarValue::Int64(v) => cast_number(*v, data_type),
+            ScalarValue::UInt8(v) => cast_number(*v, data_type),
+            ScalarValue::UInt16(v) => cast_number(*v, data_type),
+            ScalarValue::UInt32(v) => cast_number(*v, data_type),
+            ScalarValue::UInt64(v) => cast_number(*v, data_type),
+            ScalarValue::Float32(v) => cast_number(*v, data_type),
+            ScalarValue::Float64(v) => cast_number(*v, data_type),
+        };
+        value.ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Cannot cast scalar value {:?} to {:?}",
+                self, data_type
+            ))
+        })
+    }
+
+    /// Builds an array of type `data_type` from `scalars`, where each
+    /// [`ScalarValue::Null`] becomes a null slot.
+    ///
+    /// Returns an error if `data_type` is not supported, or if a value is of another
+    /// type.
+    pub fn iter_to_array<I: IntoIterator<Item = ScalarValue>>(
+        data_type: &DataType,
+        scalars: I,
+    ) -> Result<ArrayRef> {
+        let scalars = scalars.into_iter();
+
+        macro_rules! build_array {
+            ($BUILDER:ident, $SCALAR:ident) => {{
+                let mut builder = $BUILDER::new(scalars.size_hint().0);
+                for scalar in scalars {
+                    match scalar {
+                        ScalarValue::$SCALAR(v) => builder.append_value(v)?,
+                        ScalarValue::Null => builder.append_null()?,
+                        other => {
+                            return Err(ArrowError::ComputeError(format!(
+                                "Cannot build an array of type {:?} from {:?}",
+                                data_type, other
+                            )))
+                        }
+                    }
+                }
+                Arc::new(builder.finish()) as ArrayRef
+            }};
+        }
+
+        Ok(match data_type {
+            DataType::Null => {
+                let mut len = 0;
+                for scalar in scalars {
+                    if !scalar.is_null() {
+                        return Err(ArrowError::ComputeError(format!(
+                            "Cannot build an array of type Null from {:?}",
+                            scalar
+                        )));
+                    }
+                    len += 1;
+                }
+                Arc::new(NullArray::new(len)) as ArrayRef
+            }
+            DataType::Boolean => build_array!(BooleanBuilder, Boolean),
+            DataType::Int8 => build_array!(Int8Builder, Int8),
+            DataType::Int16 => build_array!(Int16Builder, Int16),
+            DataType::Int32 => build_array!(Int32Builder, Int32),
+            DataType::Int64 => build_array!(Int64Builder, Int64),
+            DataType::UInt8 => build_array!(UInt8Builder, UInt8),
+            DataType::UInt16 => build_array!(UInt16Builder, UInt16),
+            DataType::UInt32 => build_array!(UInt32Builder, UInt32),
+            DataType::UInt64 => build_array!(UInt64Builder, UInt64),
+            DataType::Float32 => build_array!(Float32Builder, Float32),
+            DataType::Float64 => build_array!(Float64Builder, Float64),
+            other => {
+                return Err(ArrowError::ComputeError(format!(
+                    "Cannot build an array of type {:?} from scalar values",
+                    other
+                )))
+            }
+        })
+    }
+}
+
+macro_rules! impl_scalar_from {
+    ($NATIVE:ty, $SCALAR:ident) => {
+        impl From<$NATIVE> for ScalarValue {
+            fn from(value: $NATIVE) -> Self {
+                ScalarValue::$SCALAR(value)
+            }
+        }
+
+        impl From<Option<$NATIVE>> for ScalarValue {
+            fn from(value: Option<$NATIVE>) -> Self {
+                value.map_or(ScalarValue::Null, ScalarValue::$SCALAR)
+            }
+        }
+    };
+}
+
+impl_scalar_from!(bool, Boolean);
+impl_scalar_from!(i8, Int8);
+impl_scalar_from!(i16, Int16);
+impl_scalar_from!(i32, Int32);
+impl_scalar_from!(i64, Int64);
+impl_scalar_from!(u8, UInt8);
+impl_scalar_from!(u16, UInt16);
+impl_scalar_from!(u32, UInt32);
+impl_scalar_from!(u64, UInt64);
+impl_scalar_from!(f32, Float32);
+impl_scalar_from!(f64, Float64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar_from_native() {
+        assert_eq!(ScalarValue::Boolean(true), ScalarValue::from(true));
+        assert_eq!(ScalarValue::Int8(-1), ScalarValue::from(-1i8));
+        assert_eq!(ScalarValue::Int16(2), ScalarValue::from(2i16));
+        assert_eq!(ScalarValue::Int32(3), ScalarValue::from(3i32));
+        assert_eq!(ScalarValue::Int64(4), ScalarValue::from(4i64));
+        assert_eq!(ScalarValue::UInt8(5), ScalarValue::from(5u8));
+        assert_eq!(ScalarValue::UInt16(6), ScalarValue::from(6u16));
+        assert_eq!(ScalarValue::UInt32(7), ScalarValue::from(7u32));
+        assert_eq!(ScalarValue::UInt64(8), ScalarValue::from(8u64));
+        assert_eq!(ScalarValue::Float32(1.5), ScalarValue::from(1.5f32));
+        assert_eq!(ScalarValue::Float64(2.5), ScalarValue::from(2.5f64));
+        assert_eq!(ScalarValue::Null, ScalarValue::from(None::<i32>));
+        assert_eq!(ScalarValue::Int32(9), ScalarValue::from(Some(9i32)));
+        assert_ne!(ScalarValue::Int32(1), ScalarValue::Int64(1));
+    }
+
+    #[test]
+    fn test_scalar_data_type() {
+        assert_eq!(DataType::Null, ScalarValue::Null.data_type());
+        assert_eq!(DataType::Boolean, ScalarValue::from(false).data_type());
+        assert_eq!(DataType::Int8, ScalarValue::from(1i8).data_type());
+        assert_eq!(DataType::Int16, ScalarValue::from(1i16).data_type());
+        assert_eq!(DataType::Int32, ScalarValue::from(1i32).data_type());
+        assert_eq!(DataType::Int64, ScalarValue::from(1i64).data_type());
+        assert_eq!(DataType::UInt8, ScalarValue::from(1u8).data_type());
+        assert_eq!(DataType::UInt16, ScalarValue::from(1u16).data_type());
+        assert_eq!(DataType::UInt32, ScalarValue::from(1u32).data_type());
+        assert_eq!(DataType::UInt64, ScalarValue::from(1u64).data_type());
+        assert_eq!(DataType::Float32, ScalarValue::from(1f32).data_type());
+        assert_eq!(DataType::Float64, ScalarValue::from(1f64).data_type());
+    }
+
+    #[test]
+    fn test_scalar_round_trip_through_array() -> Result<()> {
+        let array = Int16Array::from(vec![Some(-1), None, Some(300)]);
+        let scalars = (0..array.len())
+            .map(|i| ScalarValue::try_from_array(&array, i))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(
+            vec![
+                ScalarValue::Int16(-1),
+                ScalarValue::Null,
+                ScalarValue::Int16(300)
+            ],
+            scalars
+        );
+
+        let result = ScalarValue::iter_to_array(&DataType::Int16, scalars)?;
+        let result = result.as_any().downcast_ref::<Int16Array>().unwrap();
+        assert_eq!(&array, result);
+
+        let strings = StringArray::from(vec!["a"]);
+        assert!(ScalarValue::try_from_array(&strings, 0).is_err());
+        assert!(ScalarValue::iter_to_array(&DataType::Int8, vec![1i16.into()]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scalar_cast_to() -> Result<()> {
+        let value = ScalarValue::from(300i16);
+        assert_eq!(
+            ScalarValue::Float64(300.0),
+            value.cast_to(&DataType::Float64)?
+        );
+        assert_eq!(ScalarValue::UInt16(300), value.cast_to(&DataType::UInt16)?);
+        assert_eq!(
+            ScalarValue::Boolean(true),
+            value.cast_to(&DataType::Boolean)?
+        );
+        assert!(value.cast_to(&DataType::Int8).is_err());
+        assert!(ScalarValue::from(-1i8).cast_to(&DataType::UInt64).is_err());
+        assert!(value.cast_to(&DataType::Utf8).is_err());
+
+        assert_eq!(
+            ScalarValue::Int32(1),
+            ScalarValue::from(true).cast_to(&DataType::Int32)?
+        );
+        assert_eq!(
+            ScalarValue::Null,
+            ScalarValue::Null.cast_to(&DataType::Float32)?
+        );
+        assert!(ScalarValue::Null.cast_to(&DataType::Utf8).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scalar_is_null() {
+        assert!(ScalarValue::Null.is_null());
+        assert!(ScalarValue::from(None::<f64>).is_null());
+        assert!(!ScalarValue::from(0i32).is_null());
+        assert!(!ScalarValue::from(false).is_null());
+    }
+}
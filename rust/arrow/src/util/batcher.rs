@@ -0,0 +1,221 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A `Batcher` accepts values one row at a time and groups them into
+//! `RecordBatch`es of a fixed number of rows, so that a stream of rows can be
+//! converted to batches with bounded memory.
+
+use std::fmt;
+
+use crate::array::{ArrayBuilder, StructBuilder};
+use crate::datatypes::SchemaRef;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+/// Builds `RecordBatch`es of `batch_size` rows from values pushed row by row.
+///
+/// Values are appended to the builder of each column, obtained with
+/// `column_builder`. Once a value has been appended to every column,
+/// `finish_row` completes the row, and returns a batch whenever `batch_size`
+/// rows have been accumulated. `finish` returns the remaining rows, if any.
+pub struct Batcher {
+    schema: SchemaRef,
+    builders: Vec<Box<ArrayBuilder>>,
+    batch_size: usize,
+    num_rows: usize,
+}
+
+impl fmt::Debug for Batcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Batcher")
+            .field("schema", &self.schema)
+            .field("batch_size", &self.batch_size)
+            .field("num_rows", &self.num_rows)
+            .finish()
+    }
+}
+
+impl Batcher {
+    /// Creates a new `Batcher` with a builder for each field of `schema`
+    pub fn new(schema: SchemaRef, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch_size must be greater than 0");
+        let builders = schema
+            .fields()
+            .iter()
+            .map(|field| StructBuilder::from_field(field.clone(), batch_size))
+            .collect();
+        Self {
+            schema,
+            builders,
+            batch_size,
+            num_rows: 0,
+        }
+    }
+
+    /// Returns the schema of the batches built by this `Batcher`
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the number of completed rows that have not been returned in a batch
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns a mutable reference to the builder of the column at index `i`.
+    /// Result will be `None` if the input type `T` provided doesn't match the actual
+    /// column builder's type.
+    pub fn column_builder<T: ArrayBuilder>(&mut self, i: usize) -> Option<&mut T> {
+        self.builders[i].as_any_mut().downcast_mut::<T>()
+    }
+
+    /// Completes the current row, after a value has been appended to every column.
+    ///
+    /// Returns a batch if the row completes a batch of `batch_size` rows, and an
+    /// error if a column does not have exactly one value for the row.
+    pub fn finish_row(&mut self) -> Result<Option<RecordBatch>> {
+        let expected = self.num_rows + 1;
+        for (i, builder) in self.builders.iter().enumerate() {
+            if builder.len() != expected {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Column {} has {} values, expected {}",
+                    i,
+                    builder.len(),
+                    expected
+                )));
+            }
+        }
+        self.num_rows = expected;
+
+        if self.num_rows == self.batch_size {
+            self.flush().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns a batch with the remaining completed rows, if any
+    pub fn finish(&mut self) -> Result<Option<RecordBatch>> {
+        if self.num_rows == 0 {
+            Ok(None)
+        } else {
+            self.flush().map(Some)
+        }
+    }
+
+    fn flush(&mut self) -> Result<RecordBatch> {
+        let columns = self.builders.iter_mut().map(|b| b.finish()).collect();
+        self.num_rows = 0;
+        RecordBatch::try_new(self.schema.clone(), columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use crate::array::{
+        Array, Int32Array, Int32Builder, PrimitiveArrayOps, StringArray, StringBuilder,
+    };
+    use crate::datatypes::{DataType, Field, Schema};
+
+    #[test]
+    fn test_batcher_chunks() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let mut batcher = Batcher::new(schema.clone(), 3);
+
+        let mut batches = vec![];
+        for i in 0..7 {
+            batcher
+                .column_builder::<Int32Builder>(0)
+                .unwrap()
+                .append_value(i)?;
+            let b = batcher.column_builder::<StringBuilder>(1).unwrap();
+            if i % 2 == 0 {
+                b.append_value(&format!("row {}", i))?;
+            } else {
+                b.append_null()?;
+            }
+            if let Some(batch) = batcher.finish_row()? {
+                batches.push(batch);
+            }
+        }
+        assert_eq!(1, batcher.num_rows());
+        if let Some(batch) = batcher.finish()? {
+            batches.push(batch);
+        }
+        assert!(batcher.finish()?.is_none());
+
+        let sizes: Vec<usize> = batches.iter().map(|b| b.num_rows()).collect();
+        assert_eq!(vec![3, 3, 1], sizes);
+        assert!(batches.iter().all(|b| b.schema() == schema));
+
+        let a = batches[1]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&[3, 4, 5], a.value_slice(0, 3));
+        let b = batches[1]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert!(b.is_null(0));
+        assert_eq!("row 4", b.value(1));
+        assert_eq!(2, b.null_count());
+
+        let a = batches[2]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(6, a.value(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_batcher_missing_value_is_error() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let mut batcher = Batcher::new(schema, 2);
+        for i in 0..2 {
+            batcher
+                .column_builder::<Int32Builder>(i)
+                .unwrap()
+                .append_value(1)
+                .unwrap();
+        }
+        batcher.finish_row().unwrap();
+
+        // only the first column has a value for the second row
+        batcher
+            .column_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(2)
+            .unwrap();
+        assert!(batcher.finish_row().is_err());
+        assert_eq!(1, batcher.num_rows());
+    }
+}
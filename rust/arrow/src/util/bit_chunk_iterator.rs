@@ -72,9 +72,11 @@ impl<'a> BitChunks<'a> {
         if bit_len == 0 {
             0
         } else {
-            let byte_len = ceil(bit_len, 8);
+            // the remainder starts `offset` bits into its first byte, so it can
+            // span one more byte than its length alone requires, i.e. up to 9 bytes
+            let byte_len = ceil(bit_len + self.offset, 8);
 
-            let mut bits = 0;
+            let mut bits: u128 = 0;
             for i in 0..byte_len {
                 let byte = unsafe {
                     std::ptr::read(
@@ -82,12 +84,10 @@ impl<'a> BitChunks<'a> {
                             .add(self.chunk_len * std::mem::size_of::<u64>() + i),
                     )
                 };
-                bits |= (byte as u64) << (i * 8);
+                bits |= (byte as u128) << (i * 8);
             }
 
-            let offset = self.offset as u64;
-
-            (bits >> offset) & ((1 << bit_len) - 1)
+            ((bits >> self.offset) as u64) & ((1 << bit_len) - 1)
         }
     }
 
@@ -220,4 +220,15 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_remainder_bits_spanning_two_bytes() {
+        let input: &[u8] = &[0b11000000, 0b00000001];
+        let buffer: Buffer = Buffer::from(input);
+
+        let bitchunks = buffer.bit_chunks(6, 3);
+
+        assert_eq!(3, bitchunks.remainder_len());
+        assert_eq!(0b00000111, bitchunks.remainder_bits());
+    }
 }
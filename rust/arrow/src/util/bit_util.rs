@@ -129,6 +129,54 @@ pub unsafe fn set_bits_raw(data: *mut u8, start: usize, end: usize) {
     }
 }
 
+/// Sets the `count` bits starting at position `start` for `data`
+///
+/// Whole bytes in the range are filled at once, only the bytes at its ends are masked.
+#[inline]
+pub fn set_bits(data: &mut [u8], start: usize, count: usize) {
+    apply_bit_range(data, start, count, |byte, mask| *byte |= mask);
+}
+
+/// Sets the `count` bits starting at position `start` for `data` to 0
+///
+/// Whole bytes in the range are cleared at once, only the bytes at its ends are masked.
+#[inline]
+pub fn unset_bits(data: &mut [u8], start: usize, count: usize) {
+    apply_bit_range(data, start, count, |byte, mask| *byte &= !mask);
+}
+
+/// Applies `op` to each byte of `data` overlapping the bits `start..start + count`,
+/// with a mask of the bits of that byte within the range.
+#[inline]
+fn apply_bit_range<F: Fn(&mut u8, u8)>(
+    data: &mut [u8],
+    start: usize,
+    count: usize,
+    op: F,
+) {
+    if count == 0 {
+        return;
+    }
+    let last = start + count - 1;
+    let start_byte = start >> 3;
+    let last_byte = last >> 3;
+
+    // All set apart from the lowest `start & 7` bits
+    let start_mask = 0xFF << (start & 7);
+    // All set up to and including bit `last & 7`
+    let last_mask = 0xFF >> (7 - (last & 7));
+
+    if start_byte == last_byte {
+        op(&mut data[start_byte], start_mask & last_mask);
+    } else {
+        op(&mut data[start_byte], start_mask);
+        data[start_byte + 1..last_byte]
+            .iter_mut()
+            .for_each(|byte| op(byte, 0xFF));
+        op(&mut data[last_byte], last_mask);
+    }
+}
+
 /// Returns the number of 1-bits in `data`
 #[inline]
 pub fn count_set_bits(data: &[u8]) -> usize {
@@ -168,6 +216,27 @@ pub fn count_set_bits_offset(data: &[u8], offset: usize, length: usize) -> usize
     result
 }
 
+/// Returns whether the first `len_bits` bits of `a` and `b` are equal, ignoring any
+/// bits past `len_bits`, such as the padding at the end of a null bitmap.
+///
+/// # Panics
+///
+/// Panics if either slice holds fewer than `len_bits` bits.
+pub fn bitmaps_equal(a: &[u8], b: &[u8], len_bits: usize) -> bool {
+    let num_bytes = len_bits >> 3;
+    assert!(ceil(len_bits, 8) <= a.len() && ceil(len_bits, 8) <= b.len());
+
+    if a[..num_bytes] != b[..num_bytes] {
+        return false;
+    }
+    let remainder = len_bits & 7;
+    if remainder == 0 {
+        return true;
+    }
+    let mask = (1u8 << remainder) - 1;
+    (a[num_bytes] & mask) == (b[num_bytes] & mask)
+}
+
 /// Returns the ceil of `value`/`divisor`
 #[inline]
 pub fn ceil(value: usize, divisor: usize) -> usize {
@@ -285,6 +354,37 @@ mod tests {
         assert_eq!([0b11011010], b);
     }
 
+    #[test]
+    fn test_set_bits() {
+        let mut b = [0b00000000, 0b00000000];
+        set_bits(&mut b, 3, 8);
+        assert_eq!([0b11111000, 0b00000111], b);
+        set_bits(&mut b, 0, 0);
+        assert_eq!([0b11111000, 0b00000111], b);
+        set_bits(&mut b, 1, 1);
+        assert_eq!([0b11111010, 0b00000111], b);
+
+        let mut b = [0; 4];
+        set_bits(&mut b, 4, 24);
+        assert_eq!([0b11110000, 0xFF, 0xFF, 0b00001111], b);
+        set_bits(&mut b, 0, 32);
+        assert_eq!([0xFF; 4], b);
+    }
+
+    #[test]
+    fn test_unset_bits() {
+        let mut b = [0b11111000, 0b00000111];
+        unset_bits(&mut b, 4, 6);
+        assert_eq!([0b00001000, 0b00000100], b);
+        // unsetting bits that are already 0 keeps them 0
+        unset_bits(&mut b, 0, 3);
+        assert_eq!([0b00001000, 0b00000100], b);
+
+        let mut b = [0xFF; 4];
+        unset_bits(&mut b, 7, 18);
+        assert_eq!([0b01111111, 0x00, 0x00, 0b11111110], b);
+    }
+
     #[test]
     fn test_set_bit_raw() {
         const NUM_BYTE: usize = 10;
@@ -408,6 +508,31 @@ mod tests {
         assert_eq!(4, count_set_bits_offset(&[0b01101101, 0b10101010], 7, 9));
     }
 
+    #[test]
+    fn test_bitmaps_equal_ignores_padding() {
+        // only the bits past len_bits differ
+        assert!(bitmaps_equal(&[0b00000101], &[0b11111101], 3));
+        assert!(bitmaps_equal(
+            &[0b11111111, 0b00000001],
+            &[0b11111111, 0b10101001],
+            11
+        ));
+        assert!(bitmaps_equal(&[0b11111111, 0b00000000], &[0b11111111], 8));
+        assert!(bitmaps_equal(&[0b00000000], &[0b11111111], 0));
+    }
+
+    #[test]
+    fn test_bitmaps_not_equal() {
+        // the bits differ within len_bits
+        assert!(!bitmaps_equal(&[0b00000101], &[0b00000001], 3));
+        assert!(!bitmaps_equal(
+            &[0b11111111, 0b00000001],
+            &[0b11111111, 0b00000101],
+            11
+        ));
+        assert!(!bitmaps_equal(&[0b11111110, 0b00000001], &[0b11111111], 8));
+    }
+
     #[test]
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64")))]
     fn test_ceil() {
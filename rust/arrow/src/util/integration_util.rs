@@ -19,11 +19,16 @@
 //!
 //! These utilities define structs that read the integration JSON format for integration testing purposes.
 
+use std::convert::TryFrom;
+use std::sync::Arc;
+
 use serde_derive::{Deserialize, Serialize};
 use serde_json::{Number as VNumber, Value};
 
 use crate::array::*;
+use crate::buffer::Buffer;
 use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
 use crate::record_batch::{RecordBatch, RecordBatchReader};
 
 /// A struct that represents an Arrow file with a schema and record batches
@@ -62,12 +67,13 @@ pub struct ArrowJsonDictionaryBatch {
 pub struct ArrowJsonColumn {
     name: String,
     pub count: usize,
-    #[serde(rename = "VALIDITY")]
+    #[serde(rename = "VALIDITY", skip_serializing_if = "Option::is_none")]
     pub validity: Option<Vec<u8>>,
-    #[serde(rename = "DATA")]
+    #[serde(rename = "DATA", skip_serializing_if = "Option::is_none")]
     pub data: Option<Vec<Value>>,
-    #[serde(rename = "OFFSET")]
+    #[serde(rename = "OFFSET", skip_serializing_if = "Option::is_none")]
     pub offset: Option<Vec<Value>>, // leaving as Value as 64-bit offsets are strings
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<ArrowJsonColumn>>,
 }
 
@@ -320,58 +326,336 @@ impl ArrowJsonBatch {
             })
     }
 
-    pub fn from_batch(batch: &RecordBatch) -> ArrowJsonBatch {
-        let mut json_batch = ArrowJsonBatch {
+    /// Converts a `RecordBatch` into an Arrow JSON record batch.
+    ///
+    /// Boolean, numeric, `Utf8`, `LargeUtf8` and `List` columns are supported, an error
+    /// is returned for columns of other types.
+    pub fn from_batch(batch: &RecordBatch) -> Result<ArrowJsonBatch> {
+        Ok(ArrowJsonBatch {
             count: batch.num_rows(),
-            columns: Vec::with_capacity(batch.num_columns()),
-        };
+            columns: batch
+                .columns()
+                .iter()
+                .zip(batch.schema().fields().iter())
+                .map(|(col, field)| json_column_from_array(field.name(), col))
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
 
-        for (col, field) in batch.columns().iter().zip(batch.schema().fields.iter()) {
-            let json_col = match field.data_type() {
-                DataType::Int8 => {
-                    let col = col.as_any().downcast_ref::<Int8Array>().unwrap();
-
-                    let mut validity: Vec<u8> = Vec::with_capacity(col.len());
-                    let mut data: Vec<Value> = Vec::with_capacity(col.len());
-
-                    for i in 0..col.len() {
-                        if col.is_null(i) {
-                            validity.push(1);
-                            data.push(
-                                Int8Type::default_value().into_json_value().unwrap(),
-                            );
-                        } else {
-                            validity.push(0);
-                            data.push(col.value(i).into_json_value().unwrap());
-                        }
-                    }
+    /// Converts the Arrow JSON record batch into a `RecordBatch` with the given schema.
+    ///
+    /// The same types as in [`ArrowJsonBatch::from_batch`] are supported.
+    pub fn to_batch(&self, schema: SchemaRef) -> Result<RecordBatch> {
+        let columns = self
+            .columns
+            .iter()
+            .zip(schema.fields().iter())
+            .map(|(col, field)| array_from_json_column(col, field.data_type()))
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(schema, columns)
+    }
+}
 
-                    ArrowJsonColumn {
-                        name: field.name().clone(),
-                        count: col.len(),
-                        validity: Some(validity),
-                        data: Some(data),
-                        offset: None,
-                        children: None,
-                    }
-                }
-                _ => ArrowJsonColumn {
-                    name: field.name().clone(),
-                    count: col.len(),
-                    validity: None,
-                    data: None,
-                    offset: None,
-                    children: None,
-                },
+/// Convert an array into an Arrow JSON column/array
+fn json_column_from_array(name: &str, array: &ArrayRef) -> Result<ArrowJsonColumn> {
+    let len = array.len();
+    let mut column = ArrowJsonColumn {
+        name: name.to_string(),
+        count: len,
+        validity: Some((0..len).map(|i| array.is_valid(i) as u8).collect()),
+        data: None,
+        offset: None,
+        children: None,
+    };
+
+    match array.data_type() {
+        DataType::Boolean => column.data = Some(json_data::<BooleanType>(array)),
+        DataType::Int8 => column.data = Some(json_data::<Int8Type>(array)),
+        DataType::Int16 => column.data = Some(json_data::<Int16Type>(array)),
+        DataType::Int32 => column.data = Some(json_data::<Int32Type>(array)),
+        DataType::UInt8 => column.data = Some(json_data::<UInt8Type>(array)),
+        DataType::UInt16 => column.data = Some(json_data::<UInt16Type>(array)),
+        DataType::UInt32 => column.data = Some(json_data::<UInt32Type>(array)),
+        DataType::Float32 => column.data = Some(json_data::<Float32Type>(array)),
+        DataType::Float64 => column.data = Some(json_data::<Float64Type>(array)),
+        // 64-bit integers are written as strings
+        DataType::Int64 => column.data = Some(json_string_data::<Int64Type>(array)),
+        DataType::UInt64 => column.data = Some(json_string_data::<UInt64Type>(array)),
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+            let start = array.value_offset(0);
+            column.offset = Some(
+                (0..=len)
+                    .map(|i| (array.value_offset(i) - start).into())
+                    .collect(),
+            );
+            column.data = Some((0..len).map(|i| array.value(i).into()).collect());
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            let start = array.value_offset(0);
+            column.offset = Some(
+                (0..=len)
+                    .map(|i| (array.value_offset(i) - start).to_string().into())
+                    .collect(),
+            );
+            column.data = Some((0..len).map(|i| array.value(i).into()).collect());
+        }
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray>().unwrap();
+            let start = array.value_offset(0);
+            let end = array.value_offset(len);
+            column.offset = Some(
+                (0..=len)
+                    .map(|i| (array.value_offset(i) - start).into())
+                    .collect(),
+            );
+            let values = array.values().slice(start as usize, (end - start) as usize);
+            column.children = Some(vec![json_column_from_array("item", &values)?]);
+        }
+        t => {
+            return Err(ArrowError::JsonError(format!(
+                "Writing {:?} to the Arrow JSON integration format is not supported",
+                t
+            )))
+        }
+    }
+
+    Ok(column)
+}
+
+/// Convert the values of a primitive array into JSON values, with the default value of
+/// the type in null slots
+fn json_data<T: ArrowPrimitiveType>(array: &ArrayRef) -> Vec<Value> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    (0..array.len())
+        .map(|i| {
+            let value = if array.is_valid(i) {
+                array.value(i)
+            } else {
+                T::default_value()
             };
+            value.into_json_value().unwrap_or(Value::Null)
+        })
+        .collect()
+}
 
-            json_batch.columns.push(json_col);
+/// Convert the values of a primitive array into JSON strings, with the default value
+/// of the type in null slots
+fn json_string_data<T>(array: &ArrayRef) -> Vec<Value>
+where
+    T: ArrowPrimitiveType,
+    T::Native: ToString,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    (0..array.len())
+        .map(|i| {
+            let value = if array.is_valid(i) {
+                array.value(i)
+            } else {
+                T::default_value()
+            };
+            Value::String(value.to_string())
+        })
+        .collect()
+}
+
+/// Convert an Arrow JSON column/array into an array of the given data type
+fn array_from_json_column(
+    col: &ArrowJsonColumn,
+    data_type: &DataType,
+) -> Result<ArrayRef> {
+    if let Some(validity) = &col.validity {
+        if validity.len() < col.count {
+            return Err(ArrowError::JsonError(format!(
+                "Column {} has {} VALIDITY entries, expected {}",
+                col.name,
+                validity.len(),
+                col.count
+            )));
         }
+    }
 
-        json_batch
+    match data_type {
+        DataType::Boolean => {
+            primitive_array_from_json::<BooleanType, _>(col, |v| v.as_bool())
+        }
+        DataType::Int8 => primitive_array_from_json::<Int8Type, _>(col, |v| {
+            json_as_i64(v).and_then(|v| i8::try_from(v).ok())
+        }),
+        DataType::Int16 => primitive_array_from_json::<Int16Type, _>(col, |v| {
+            json_as_i64(v).and_then(|v| i16::try_from(v).ok())
+        }),
+        DataType::Int32 => primitive_array_from_json::<Int32Type, _>(col, |v| {
+            json_as_i64(v).and_then(|v| i32::try_from(v).ok())
+        }),
+        DataType::Int64 => primitive_array_from_json::<Int64Type, _>(col, json_as_i64),
+        DataType::UInt8 => primitive_array_from_json::<UInt8Type, _>(col, |v| {
+            json_as_u64(v).and_then(|v| u8::try_from(v).ok())
+        }),
+        DataType::UInt16 => primitive_array_from_json::<UInt16Type, _>(col, |v| {
+            json_as_u64(v).and_then(|v| u16::try_from(v).ok())
+        }),
+        DataType::UInt32 => primitive_array_from_json::<UInt32Type, _>(col, |v| {
+            json_as_u64(v).and_then(|v| u32::try_from(v).ok())
+        }),
+        DataType::UInt64 => primitive_array_from_json::<UInt64Type, _>(col, json_as_u64),
+        DataType::Float32 => primitive_array_from_json::<Float32Type, _>(col, |v| {
+            v.as_f64().map(|v| v as f32)
+        }),
+        DataType::Float64 => {
+            primitive_array_from_json::<Float64Type, _>(col, |v| v.as_f64())
+        }
+        DataType::Utf8 => {
+            let data = json_column_buffer(col, &col.data, "DATA", col.count)?;
+            let mut builder = StringBuilder::new(col.count);
+            for i in 0..col.count {
+                if json_is_valid(col, i) {
+                    builder.append_value(json_as_str(&data[i])?)?;
+                } else {
+                    builder.append_null()?;
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        DataType::LargeUtf8 => {
+            let data = json_column_buffer(col, &col.data, "DATA", col.count)?;
+            let mut builder = LargeStringBuilder::new(col.count);
+            for i in 0..col.count {
+                if json_is_valid(col, i) {
+                    builder.append_value(json_as_str(&data[i])?)?;
+                } else {
+                    builder.append_null()?;
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        DataType::List(value_type) => {
+            let offsets = json_column_buffer(col, &col.offset, "OFFSET", col.count + 1)?
+                .iter()
+                .map(|v| {
+                    json_as_i64(v)
+                        .and_then(|v| i32::try_from(v).ok())
+                        .ok_or_else(|| {
+                            ArrowError::JsonError(format!("Invalid list offset {}", v))
+                        })
+                })
+                .collect::<Result<Vec<i32>>>()?;
+            let child = col
+                .children
+                .as_ref()
+                .and_then(|children| children.first())
+                .ok_or_else(|| {
+                    ArrowError::JsonError(format!(
+                        "List column {} has no children",
+                        col.name
+                    ))
+                })?;
+            let values = array_from_json_column(child, value_type)?;
+
+            let mut builder = ArrayData::builder(data_type.clone())
+                .len(col.count)
+                .add_buffer(Buffer::from(offsets.to_byte_slice()))
+                .add_child_data(values.data());
+            if (0..col.count).any(|i| !json_is_valid(col, i)) {
+                let mut null_buf = BooleanBufferBuilder::new(col.count);
+                for i in 0..col.count {
+                    null_buf.append(json_is_valid(col, i))?;
+                }
+                builder = builder.null_bit_buffer(null_buf.finish());
+            }
+            Ok(make_array(builder.build()))
+        }
+        t => Err(ArrowError::JsonError(format!(
+            "Reading {:?} from the Arrow JSON integration format is not supported",
+            t
+        ))),
     }
 }
 
+/// Convert an Arrow JSON column/array into a primitive array, parsing its values with
+/// `parse`
+fn primitive_array_from_json<T, F>(col: &ArrowJsonColumn, parse: F) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(&Value) -> Option<T::Native>,
+{
+    let data = json_column_buffer(col, &col.data, "DATA", col.count)?;
+    let mut builder = PrimitiveBuilder::<T>::new(col.count);
+    for i in 0..col.count {
+        if json_is_valid(col, i) {
+            let value = parse(&data[i]).ok_or_else(|| {
+                ArrowError::JsonError(format!(
+                    "Cannot parse {} as {:?}",
+                    data[i],
+                    T::DATA_TYPE
+                ))
+            })?;
+            builder.append_value(value)?;
+        } else {
+            builder.append_null()?;
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Returns a buffer of an Arrow JSON column/array, checking that it is present and has
+/// at least `len` entries
+fn json_column_buffer<'a>(
+    col: &ArrowJsonColumn,
+    buffer: &'a Option<Vec<Value>>,
+    buffer_name: &str,
+    len: usize,
+) -> Result<&'a [Value]> {
+    let buffer = buffer.as_deref().ok_or_else(|| {
+        ArrowError::JsonError(format!("Column {} has no {}", col.name, buffer_name))
+    })?;
+    if buffer.len() < len {
+        return Err(ArrowError::JsonError(format!(
+            "Column {} has {} {} entries, expected {}",
+            col.name,
+            buffer.len(),
+            buffer_name,
+            len
+        )));
+    }
+    Ok(buffer)
+}
+
+/// Returns whether slot `i` of an Arrow JSON column/array is valid
+///
+/// The validity of the column must have been checked to cover slot `i`.
+fn json_is_valid(col: &ArrowJsonColumn, i: usize) -> bool {
+    col.validity
+        .as_ref()
+        .map_or(true, |validity| validity[i] == 1)
+}
+
+/// Parse a JSON number or string as an `i64`
+fn json_as_i64(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Parse a JSON number or string as a `u64`
+fn json_as_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Returns the string of a JSON string value
+fn json_as_str(value: &Value) -> Result<&str> {
+    value.as_str().ok_or_else(|| {
+        ArrowError::JsonError(format!("Expected a string, found {}", value))
+    })
+}
+
 /// Convert an Arrow JSON column/array into a vector of `Value`
 fn json_from_col(col: &ArrowJsonColumn, data_type: &DataType) -> Vec<Value> {
     match data_type {
@@ -799,4 +1083,43 @@ mod tests {
         // test record batch
         assert!(arrow_json.batches[0].equals_batch(&record_batch));
     }
+
+    #[test]
+    fn test_json_batch_unsupported_type() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Date32(DateUnit::Day),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Date32Array::from(vec![Some(1), None]))],
+        )
+        .unwrap();
+        assert!(ArrowJsonBatch::from_batch(&batch).is_err());
+    }
+
+    #[test]
+    fn test_json_batch_short_buffers() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batch = |column: Value| -> ArrowJsonBatch {
+            serde_json::from_value(serde_json::json!({"count": 3, "columns": [column]}))
+                .unwrap()
+        };
+
+        let valid = batch(serde_json::json!(
+            {"name": "a", "count": 3, "VALIDITY": [1, 0, 1], "DATA": [1, 0, 3]}
+        ));
+        assert_eq!(3, valid.to_batch(schema.clone()).unwrap().num_rows());
+
+        let short_data = batch(serde_json::json!(
+            {"name": "a", "count": 3, "VALIDITY": [1, 1, 1], "DATA": [1, 2]}
+        ));
+        assert!(short_data.to_batch(schema.clone()).is_err());
+
+        let short_validity = batch(serde_json::json!(
+            {"name": "a", "count": 3, "VALIDITY": [1, 1], "DATA": [1, 2, 3]}
+        ));
+        assert!(short_validity.to_batch(schema).is_err());
+    }
 }
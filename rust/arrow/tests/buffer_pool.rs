@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests `memory::allocated_bytes` and `memory::BufferPool`.
+//!
+//! The allocation counter is global, so these checks live in their own test binary
+//! with a single test, where no other test can allocate concurrently.
+
+#![cfg(feature = "buffer_pool")]
+
+use arrow::buffer::MutableBuffer;
+use arrow::memory::{allocated_bytes, BufferPool};
+
+#[test]
+fn test_allocated_bytes() {
+    let before = allocated_bytes();
+
+    // allocating and freeing moves the counter up and back down
+    let mut buffer = MutableBuffer::new(1000);
+    assert_eq!(before + buffer.capacity() as isize, allocated_bytes());
+    buffer.reserve(5000).unwrap();
+    assert_eq!(before + buffer.capacity() as isize, allocated_bytes());
+    drop(buffer);
+    assert_eq!(before, allocated_bytes());
+
+    // acquiring after releasing reuses the pooled buffer without allocating
+    let pool = BufferPool::new(1);
+    let buffer = pool.acquire(1000);
+    let capacity = buffer.capacity();
+    let allocated = allocated_bytes();
+    assert_eq!(before + capacity as isize, allocated);
+    pool.release(buffer);
+    assert_eq!(allocated, allocated_bytes());
+    let buffer = pool.acquire(1000);
+    assert!(buffer.capacity() >= 1000);
+    assert_eq!(allocated, allocated_bytes());
+
+    // buffers past the limit of the pool are freed
+    pool.release(buffer);
+    pool.release(MutableBuffer::new(capacity));
+    assert_eq!(allocated, allocated_bytes());
+
+    drop(pool);
+    assert_eq!(before, allocated_bytes());
+}
@@ -23,7 +23,7 @@ use arrow::util::pretty;
 
 use arrow_flight::flight_descriptor;
 use arrow_flight::flight_service_client::FlightServiceClient;
-use arrow_flight::utils::flight_data_to_arrow_batch;
+use arrow_flight::utils::flight_data_to_batch;
 use arrow_flight::{FlightDescriptor, Ticket};
 
 /// This example shows how to wrap DataFusion with `FlightService` to support looking up schema information for
@@ -63,11 +63,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // all the remaining stream messages should be dictionary and record batches
     let mut results = vec![];
+    let mut dictionaries_by_field = vec![None; schema.fields().len()];
     while let Some(flight_data) = stream.message().await? {
-        // the unwrap is infallible and thus safe
-        let record_batch =
-            flight_data_to_arrow_batch(&flight_data, schema.clone()).unwrap()?;
-        results.push(record_batch);
+        if let Some(record_batch) = flight_data_to_batch(
+            &flight_data,
+            schema.clone(),
+            &mut dictionaries_by_field,
+        )? {
+            results.push(record_batch);
+        }
     }
 
     // print the results
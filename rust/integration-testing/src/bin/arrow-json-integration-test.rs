@@ -432,7 +432,7 @@ fn arrow_to_json(arrow_name: &str, json_name: &str, verbose: bool) -> Result<()>
     let schema = ArrowJsonSchema { fields };
 
     let batches = reader
-        .map(|batch| Ok(ArrowJsonBatch::from_batch(&batch?)))
+        .map(|batch| Ok(ArrowJsonBatch::from_batch(&batch?)?))
         .collect::<Result<Vec<_>>>()?;
 
     let arrow_json = ArrowJson {
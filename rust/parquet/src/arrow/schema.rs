@@ -270,6 +270,9 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
         DataType::LargeUtf8 | DataType::LargeBinary | DataType::LargeList(_) => {
             Err(ArrowError("Large arrays not supported".to_string()))
         }
+        DataType::Utf8View => {
+            Err(ArrowError("Utf8View arrays not supported".to_string()))
+        }
     }
 }
 /// This struct is used to group methods and data structures used to convert parquet